@@ -0,0 +1,66 @@
+/* dec64.h
+
+Generated from src/ffi.rs by tests/header.rs, do not edit.
+
+Link with the dec64 crate built with the `c-api` feature, as a cdylib or a
+staticlib:
+
+    cargo rustc --release --features c-api --crate-type cdylib
+    cargo rustc --release --features c-api --crate-type staticlib
+
+The functions have the names and signatures of the reference dec64.h, and
+behave like it.
+
+To change this file, change the list in src/ffi.rs and run the tests with
+DEC64_BLESS=1 set, which writes it anew.
+*/
+
+#ifndef DEC64_H
+#define DEC64_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef long long int64;
+typedef int64 dec64;
+
+#define DEC64_NAN   0x80LL
+#define DEC64_ZERO  0x00LL
+#define DEC64_ONE   0x100LL
+#define DEC64_TRUE  0x380LL
+#define DEC64_FALSE 0x280LL
+
+dec64 dec64_abs(dec64 number);
+dec64 dec64_add(dec64 augend, dec64 addend);
+dec64 dec64_ceiling(dec64 number);
+int64 dec64_coefficient(dec64 number);
+dec64 dec64_dec(dec64 minuend);
+dec64 dec64_divide(dec64 dividend, dec64 divisor);
+int64 dec64_exponent(dec64 number);
+dec64 dec64_floor(dec64 number);
+dec64 dec64_half(dec64 dividend);
+dec64 dec64_inc(dec64 augend);
+dec64 dec64_int(dec64 number);
+dec64 dec64_integer_divide(dec64 dividend, dec64 divisor);
+dec64 dec64_is_equal(dec64 comparahend, dec64 comparator);
+dec64 dec64_is_false(dec64 boolean);
+dec64 dec64_is_integer(dec64 number);
+dec64 dec64_is_less(dec64 comparahend, dec64 comparator);
+dec64 dec64_is_nan(dec64 number);
+dec64 dec64_is_zero(dec64 number);
+dec64 dec64_modulo(dec64 dividend, dec64 divisor);
+dec64 dec64_multiply(dec64 multiplicand, dec64 multiplier);
+dec64 dec64_neg(dec64 number);
+dec64 dec64_new(int64 coefficient, int64 exponent);
+dec64 dec64_normal(dec64 number);
+dec64 dec64_not(dec64 boolean);
+dec64 dec64_round(dec64 number, dec64 place);
+dec64 dec64_signum(dec64 number);
+dec64 dec64_subtract(dec64 minuend, dec64 subtrahend);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
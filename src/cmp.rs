@@ -0,0 +1,97 @@
+//! Ordering of DEC64 numbers.
+
+use std::cmp::Ordering;
+
+use super::{align, first_nan, Dec64};
+use reason::pass_nan;
+
+impl PartialOrd for Dec64 {
+    /// Compare two DEC64 numbers, across exponents.
+    /// NaNs are unordered, except that a NaN equals itself as with `PartialEq`.
+    fn partial_cmp(&self, other: &Dec64) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return if self.value == other.value { Some(Ordering::Equal) } else { None };
+        }
+
+        // Numbers with the same exponent compare by their coefficients.
+        if self.exponent() == other.exponent() {
+            return Some(self.coefficient().cmp(&other.coefficient()));
+        }
+
+        // Otherwise bring them to the same exponent. Should the one with the smaller
+        // exponent lose digits doing so, the other one is far larger in magnitude
+        // anyway, which keeps the ordering intact.
+        let lhs = (self.coefficient() as i128, self.exponent() as i32);
+        let rhs = (other.coefficient() as i128, other.exponent() as i32);
+        let ordering = if lhs.1 > rhs.1 {
+            let (lhs, rhs, _, _) = align(lhs, rhs, i128::MAX);
+            lhs.cmp(&rhs)
+        } else {
+            let (rhs, lhs, _, _) = align(rhs, lhs, i128::MAX);
+            lhs.cmp(&rhs)
+        };
+
+        Some(ordering)
+    }
+}
+
+impl Dec64 {
+    /// Returns the minimum of the two numbers, ignoring NaN.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    pub fn min(self, other: Dec64) -> Dec64 {
+        if self.is_nan() || other < self { other } else { self }
+    }
+
+    /// Returns the maximum of the two numbers, ignoring NaN.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    pub fn max(self, other: Dec64) -> Dec64 {
+        if self.is_nan() || other > self { other } else { self }
+    }
+
+    /// Returns the minimum of the two numbers, propagating NaN.
+    ///
+    /// If one of the arguments is NaN, then NaN is returned.
+    pub fn minimum(self, other: Dec64) -> Dec64 {
+        if self.is_nan() || other.is_nan() {
+            first_nan(self, other)
+        } else {
+            self.min(other)
+        }
+    }
+
+    /// Returns the maximum of the two numbers, propagating NaN.
+    ///
+    /// If one of the arguments is NaN, then NaN is returned.
+    pub fn maximum(self, other: Dec64) -> Dec64 {
+        if self.is_nan() || other.is_nan() {
+            first_nan(self, other)
+        } else {
+            self.max(other)
+        }
+    }
+
+    /// Restricts the number to a certain interval, unless it is NaN.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
+    /// less than `min`. Otherwise this returns `self`. Returns NaN if the number
+    /// is NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    pub fn clamp(self, min: Dec64, max: Dec64) -> Dec64 {
+        assert!(!min.is_nan() && !max.is_nan() && min <= max, "min > max, or either was NaN. min = {:?}, max = {:?}", min, max);
+
+        if self.is_nan() {
+            pass_nan(self)
+        } else if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
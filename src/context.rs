@@ -0,0 +1,220 @@
+//! Arithmetic contexts, holding a rounding mode and sticky status flags.
+//!
+//! Each thread has a current context, which `Dec64::pack`, and with it the
+//! operators, round in and record their status to. A `Context` of its own
+//! gives a computation its own rounding mode and flags, through its methods.
+
+use std::cell::Cell;
+
+use super::{first_nan, quotient, sum, Dec64, RoundingMode};
+use reason::NanReason;
+use round::{round_pack, try_round_pack};
+
+/// Sticky status flags. Once raised, a flag stays raised until the flags
+/// are cleared.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Flags {
+    /// A result was rounded, dropping nonzero digits.
+    pub inexact: bool,
+    /// A result was too large to be represented, and became NaN.
+    pub overflow: bool,
+    /// A result was too tiny to be represented exactly, and was rounded, possibly to zero.
+    pub underflow: bool,
+    /// An operation had no meaningful result, such as zero divided by zero.
+    pub invalid: bool,
+    /// A nonzero number was divided by zero.
+    pub division_by_zero: bool,
+}
+
+impl Flags {
+    /// Returns `true` if no flag is raised, and `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        *self == Flags::default()
+    }
+}
+
+/// A rounding mode, and the status flags raised by the operations done in it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Context {
+    rounding: RoundingMode,
+    flags: Flags,
+}
+
+thread_local! {
+    static CURRENT: Cell<Context> = Cell::new(Context::default());
+}
+
+/// Rounds in the current thread's context, recording the status there.
+pub(crate) fn round_current(coefficient: i128, exponent: i32, sticky: bool) -> Dec64 {
+    CURRENT.with(|current| {
+        let mut context = current.get();
+        let result = round_pack(coefficient, exponent, sticky, context.rounding, &mut context.flags);
+        current.set(context);
+
+        result
+    })
+}
+
+/// Rounds in the current thread's context as `round_current` does, but
+/// returns `None` for numbers that are too huge. The context is then left
+/// as it was.
+pub(crate) fn try_round_current(coefficient: i128, exponent: i32, sticky: bool) -> Option<Dec64> {
+    CURRENT.with(|current| {
+        let mut context = current.get();
+        let result = try_round_pack(coefficient, exponent, sticky, context.rounding, &mut context.flags)?;
+        current.set(context);
+
+        Some(result)
+    })
+}
+
+/// Runs an operation in the current thread's context, keeping the flags it raises.
+pub(crate) fn with_current<T, F: FnOnce(&mut Context) -> T>(operation: F) -> T {
+    CURRENT.with(|current| {
+        let mut context = current.get();
+        let result = operation(&mut context);
+        current.set(context);
+
+        result
+    })
+}
+
+/// Raises flags in the current thread's context.
+pub(crate) fn raise_current<F: FnOnce(&mut Flags)>(raise: F) {
+    CURRENT.with(|current| {
+        let mut context = current.get();
+        raise(&mut context.flags);
+        current.set(context);
+    });
+}
+
+impl Context {
+    /// Constructs a context with the given rounding mode and no flags raised.
+    pub fn new(rounding: RoundingMode) -> Self {
+        Context { rounding, flags: Flags::default() }
+    }
+
+    /// Returns a copy of the current thread's context, with the flags raised so far.
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Replaces the current thread's context.
+    pub fn set_current(context: Context) {
+        CURRENT.with(|current| current.set(context));
+    }
+
+    /// Returns the rounding mode.
+    #[inline]
+    pub fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    /// Sets the rounding mode.
+    #[inline]
+    pub fn set_rounding(&mut self, rounding: RoundingMode) {
+        self.rounding = rounding;
+    }
+
+    /// Returns the flags raised so far.
+    #[inline]
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Lowers all flags.
+    #[inline]
+    pub fn clear_flags(&mut self) {
+        self.flags = Flags::default();
+    }
+
+    /// Combines the coefficient and exponent into a DEC64 like `Dec64::pack`,
+    /// but rounding in this context.
+    pub fn pack(&mut self, coefficient: i64, exponent: i32) -> Dec64 {
+        operation!("Context::pack", coefficient, exponent);
+
+        round_pack(coefficient as i128, exponent, false, self.rounding, &mut self.flags)
+    }
+
+    /// Adds two numbers, rounding in this context.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if either number is NaN.
+    pub fn add(&mut self, a: Dec64, b: Dec64) -> Dec64 {
+        operation!("Context::add", a, b);
+
+        if a.is_nan() || b.is_nan() {
+            return first_nan(a, b);
+        }
+
+        self.add_parts(parts(a), parts(b))
+    }
+
+    /// Subtracts `b` from `a`, rounding in this context.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if either number is NaN.
+    pub fn sub(&mut self, a: Dec64, b: Dec64) -> Dec64 {
+        operation!("Context::sub", a, b);
+
+        if a.is_nan() || b.is_nan() {
+            return first_nan(a, b);
+        }
+
+        let (coefficient, exponent) = parts(b);
+
+        self.add_parts(parts(a), (-coefficient, exponent))
+    }
+
+    /// Multiplies two numbers, rounding in this context.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if either number is NaN.
+    pub fn mul(&mut self, a: Dec64, b: Dec64) -> Dec64 {
+        operation!("Context::mul", a, b);
+
+        if a.is_nan() || b.is_nan() {
+            return first_nan(a, b);
+        }
+
+        // The product of two coefficients takes at most 111 bits, so it is exact.
+        let product = a.coefficient() as i128 * b.coefficient() as i128;
+
+        round_pack(product, a.exponent() as i32 + b.exponent() as i32, false, self.rounding, &mut self.flags)
+    }
+
+    /// Divides `a` by `b`, rounding in this context.
+    ///
+    /// Returns NaN, raising `division_by_zero`, if `b` is zero. Raises `invalid`
+    /// instead if `a` is zero too. Also returns NaN if the result is too large
+    /// to be represented, or if either number is NaN.
+    pub fn div(&mut self, a: Dec64, b: Dec64) -> Dec64 {
+        operation!("Context::div", a, b);
+
+        if a.is_nan() || b.is_nan() {
+            return first_nan(a, b);
+        } else if b.is_zero() {
+            return if a.is_zero() {
+                nan!(in &mut self.flags; NanReason::Invalid, "Context::div", a, b)
+            } else {
+                nan!(in &mut self.flags; NanReason::DivisionByZero, "Context::div", a, b)
+            };
+        }
+
+        let (quotient, exponent, sticky) = quotient(
+            a.coefficient() as i128,
+            b.coefficient() as i128,
+            a.exponent() as i32 - b.exponent() as i32,
+        );
+
+        round_pack(quotient, exponent, sticky, self.rounding, &mut self.flags)
+    }
+
+    fn add_parts(&mut self, a: (i128, i32), b: (i128, i32)) -> Dec64 {
+        // Leave a bit of headroom so that the sum cannot overflow.
+        let (sum, exponent, sticky) = sum(a, b, i128::MAX / 2);
+
+        round_pack(sum, exponent, sticky, self.rounding, &mut self.flags)
+    }
+}
+
+fn parts(value: Dec64) -> (i128, i32) {
+    (value.coefficient() as i128, value.exponent() as i32)
+}
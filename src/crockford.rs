@@ -0,0 +1,272 @@
+//! The function interface of the reference `dec64.h`, for porting C code
+//! that uses it.
+//!
+//! These functions follow the reference rather than the rest of this crate.
+//! They always round half away from zero, whatever the current `Context`.
+//! Every NaN they return is `NAN` and every zero is `ZERO`, so payloads are
+//! not passed on. Zero times or divided by anything, NaN included, is zero.
+//! The predicates return `DEC64_TRUE` or `DEC64_FALSE`, not a `bool`.
+
+use super::{Context, Dec64, RoundingMode, MAX_COEFFICIENT, MIN_COEFFICIENT, NAN, ZERO};
+use more_consts::normal::{ONE, TWO};
+
+/// The true of the reference, a NaN with the coefficient 3.
+pub const DEC64_TRUE: Dec64 = dec64_raw!(0x380);
+/// The false of the reference, a NaN with the coefficient 2.
+pub const DEC64_FALSE: Dec64 = dec64_raw!(0x280);
+
+/// Turns any NaN into `NAN` and any zero into `ZERO`, as the reference
+/// produces only those.
+fn standard(number: Dec64) -> Dec64 {
+    if number.is_nan() {
+        NAN
+    } else if number.coefficient() == 0 {
+        ZERO
+    } else {
+        number
+    }
+}
+
+fn boolean(value: bool) -> Dec64 {
+    if value { DEC64_TRUE } else { DEC64_FALSE }
+}
+
+fn context() -> Context {
+    Context::new(RoundingMode::HalfAwayFromZero)
+}
+
+/// Rounds the number to an integer in the given mode.
+fn integer(number: Dec64, mode: RoundingMode) -> Dec64 {
+    if number.is_nan() || number.exponent() >= 0 {
+        return standard(number);
+    }
+
+    // Dropping digits can not make the coefficient too long.
+    standard(number.rescale(0, mode).map_or(NAN, |(number, _)| number))
+}
+
+/// Combines the coefficient and exponent into a number. Numbers that are too
+/// huge become NaN, and numbers that are too tiny become zero.
+pub fn dec64_new(coefficient: i64, exponent: i64) -> Dec64 {
+    operation!("dec64_new", coefficient, exponent);
+
+    // Far beyond the range of exponents, every number is either zero or NaN.
+    let exponent = exponent.clamp(-1000, 1000) as i32;
+
+    standard(Dec64::pack_with(coefficient, exponent, RoundingMode::HalfAwayFromZero))
+}
+
+/// Returns the coefficient of the number.
+pub fn dec64_coefficient(number: Dec64) -> i64 {
+    number.coefficient()
+}
+
+/// Returns the exponent of the number, which is `-128` for NaN.
+pub fn dec64_exponent(number: Dec64) -> i64 {
+    number.exponent() as i64
+}
+
+/// Brings the exponent as close to zero as possible without losing any
+/// significance, so `12e2` becomes `1200` and `1200e-2` becomes `12`.
+pub fn dec64_normal(number: Dec64) -> Dec64 {
+    if number.is_nan() || number.coefficient() == 0 {
+        return standard(number);
+    }
+
+    let mut coefficient = number.coefficient();
+    let mut exponent = number.exponent() as i32;
+    while exponent < 0 && coefficient % 10 == 0 {
+        coefficient /= 10;
+        exponent += 1;
+    }
+    while exponent > 0 && (MIN_COEFFICIENT / 10..=MAX_COEFFICIENT / 10).contains(&coefficient) {
+        coefficient *= 10;
+        exponent -= 1;
+    }
+
+    dec64_parts!(coefficient, exponent)
+}
+
+/// Negates the number.
+pub fn dec64_neg(number: Dec64) -> Dec64 {
+    operation!("dec64_neg", number);
+
+    dec64_subtract(ZERO, number)
+}
+
+/// Returns the absolute value of the number.
+pub fn dec64_abs(number: Dec64) -> Dec64 {
+    operation!("dec64_abs", number);
+
+    if number.is_sign_negative() { dec64_neg(number) } else { standard(number) }
+}
+
+/// Adds two numbers.
+pub fn dec64_add(augend: Dec64, addend: Dec64) -> Dec64 {
+    operation!("dec64_add", augend, addend);
+
+    standard(context().add(augend, addend))
+}
+
+/// Subtracts the second number from the first.
+pub fn dec64_subtract(minuend: Dec64, subtrahend: Dec64) -> Dec64 {
+    operation!("dec64_subtract", minuend, subtrahend);
+
+    standard(context().sub(minuend, subtrahend))
+}
+
+/// Adds one to the number.
+pub fn dec64_inc(augend: Dec64) -> Dec64 {
+    operation!("dec64_inc", augend);
+
+    dec64_add(augend, ONE)
+}
+
+/// Subtracts one from the number.
+pub fn dec64_dec(minuend: Dec64) -> Dec64 {
+    operation!("dec64_dec", minuend);
+
+    dec64_subtract(minuend, ONE)
+}
+
+/// Multiplies two numbers. If either is zero, the product is zero even if
+/// the other is NaN.
+pub fn dec64_multiply(multiplicand: Dec64, multiplier: Dec64) -> Dec64 {
+    operation!("dec64_multiply", multiplicand, multiplier);
+
+    if multiplicand.is_zero() || multiplier.is_zero() {
+        return ZERO;
+    }
+
+    standard(context().mul(multiplicand, multiplier))
+}
+
+/// Divides two numbers. If the dividend is zero, the quotient is zero even
+/// if the divisor is zero or NaN. Otherwise dividing by zero gives NaN.
+pub fn dec64_divide(dividend: Dec64, divisor: Dec64) -> Dec64 {
+    operation!("dec64_divide", dividend, divisor);
+
+    if dividend.is_zero() {
+        return ZERO;
+    }
+
+    standard(context().div(dividend, divisor))
+}
+
+/// Divides the number by two.
+pub fn dec64_half(dividend: Dec64) -> Dec64 {
+    dec64_divide(dividend, TWO)
+}
+
+/// Divides two numbers, with a floored integer result. This is
+/// `dec64_floor(dec64_divide(dividend, divisor))`.
+pub fn dec64_integer_divide(dividend: Dec64, divisor: Dec64) -> Dec64 {
+    operation!("dec64_integer_divide", dividend, divisor);
+
+    dec64_floor(dec64_divide(dividend, divisor))
+}
+
+/// The remainder of a floored division, which has the sign of the divisor.
+/// This is `dividend - divisor * dec64_integer_divide(dividend, divisor)`.
+pub fn dec64_modulo(dividend: Dec64, divisor: Dec64) -> Dec64 {
+    operation!("dec64_modulo", dividend, divisor);
+
+    let quotient = dec64_integer_divide(dividend, divisor);
+    if quotient.is_nan() {
+        return NAN;
+    }
+
+    dec64_subtract(dividend, dec64_multiply(divisor, quotient))
+}
+
+/// Rounds the number, half away from zero, at the decimal place given as a
+/// power of ten: `-2` for the nearest cent, `0` for the nearest integer, `3`
+/// for the nearest thousand. A NaN place is taken as zero.
+///
+/// Returns NaN if the number is NaN, or if the place is not an integer.
+pub fn dec64_round(number: Dec64, place: Dec64) -> Dec64 {
+    let place = if place.is_nan() {
+        0
+    } else if let Some(place) = place.as_integer() {
+        place.clamp(-127, 127) as i8
+    } else if place.is_integer() {
+        // Too large for an i64, and so far beyond the range of exponents.
+        if place.is_sign_negative() { -127 } else { 127 }
+    } else {
+        return NAN;
+    };
+    if number.is_nan() || number.exponent() >= place {
+        return standard(number);
+    }
+
+    standard(number.rescale(place, RoundingMode::HalfAwayFromZero).map_or(NAN, |(number, _)| number))
+}
+
+/// Rounds the number down to an integer.
+pub fn dec64_floor(number: Dec64) -> Dec64 {
+    integer(number, RoundingMode::Floor)
+}
+
+/// Rounds the number up to an integer.
+pub fn dec64_ceiling(number: Dec64) -> Dec64 {
+    integer(number, RoundingMode::Ceiling)
+}
+
+/// Rounds the number toward zero to an integer, discarding the fraction.
+pub fn dec64_int(number: Dec64) -> Dec64 {
+    integer(number, RoundingMode::TowardZero)
+}
+
+/// Returns `DEC64_TRUE` if the numbers are equal. Zeros of any exponent are
+/// equal, but NaNs are equal only to the very same NaN.
+pub fn dec64_is_equal(comparahend: Dec64, comparator: Dec64) -> Dec64 {
+    boolean(comparahend == comparator)
+}
+
+/// Returns `DEC64_TRUE` if the first number is less than the second. Any NaN
+/// is greater than any number, and NaNs compare by their coefficients.
+pub fn dec64_is_less(comparahend: Dec64, comparator: Dec64) -> Dec64 {
+    boolean(match (comparahend.is_nan(), comparator.is_nan()) {
+        (false, false) => comparahend < comparator,
+        (true, true) => comparahend.coefficient() < comparator.coefficient(),
+        (nan, _) => !nan,
+    })
+}
+
+/// Returns `DEC64_TRUE` if the boolean is `DEC64_FALSE`, and `DEC64_FALSE`
+/// for anything else.
+pub fn dec64_is_false(boolean: Dec64) -> Dec64 {
+    self::boolean(boolean == DEC64_FALSE)
+}
+
+/// Returns `DEC64_FALSE` for `DEC64_TRUE` and `DEC64_TRUE` for `DEC64_FALSE`.
+/// Anything else is not a boolean, and gives NaN.
+pub fn dec64_not(boolean: Dec64) -> Dec64 {
+    if boolean == DEC64_TRUE {
+        DEC64_FALSE
+    } else if boolean == DEC64_FALSE {
+        DEC64_TRUE
+    } else {
+        NAN
+    }
+}
+
+/// Returns `DEC64_TRUE` if the number is any NaN.
+pub fn dec64_is_nan(number: Dec64) -> Dec64 {
+    boolean(number.is_nan())
+}
+
+/// Returns `DEC64_TRUE` if the number is zero, of any exponent.
+pub fn dec64_is_zero(number: Dec64) -> Dec64 {
+    boolean(number.is_zero())
+}
+
+/// Returns `DEC64_TRUE` if the number has no fractional part. NaN is not an integer.
+pub fn dec64_is_integer(number: Dec64) -> Dec64 {
+    boolean(number.is_integer())
+}
+
+/// Returns `-1`, `0` or `1` for a negative, zero or positive number, and NaN for NaN.
+pub fn dec64_signum(number: Dec64) -> Dec64 {
+    standard(number.signum())
+}
@@ -0,0 +1,163 @@
+//! Exponential and logarithm functions.
+//!
+//! These are computed in decimal integer arithmetic only, so the results
+//! are identical on every platform.
+
+use super::{first_nan, Dec64, ZERO};
+use reason::{pass_nan, NanReason};
+use more_consts::normal::ONE;
+use wide::{self, Wide};
+
+impl Dec64 {
+    /// Returns `e^(self)`, the exponential function.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if the number is NaN.
+    pub fn exp(self) -> Dec64 {
+        operation!("Dec64::exp", self);
+
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        Wide::from_dec64(self).exp().to_dec64()
+    }
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// Returns NaN if the number is not positive, or if it is NaN.
+    pub fn ln(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if self.coefficient() <= 0 {
+            return nan!(NanReason::Invalid, "Dec64::ln", self);
+        }
+
+        Wide::from_dec64(self).ln().to_dec64()
+    }
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// Powers of ten have exact integer logarithms.
+    /// Returns NaN if the number is not positive, or if it is NaN.
+    pub fn log10(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if self.coefficient() <= 0 {
+            return nan!(NanReason::Invalid, "Dec64::log10", self);
+        }
+
+        let mut coefficient = self.coefficient();
+        let mut exponent = self.exponent() as i64;
+        while coefficient % 10 == 0 {
+            coefficient /= 10;
+            exponent += 1;
+        }
+        if coefficient == 1 {
+            return Dec64::from(exponent);
+        }
+
+        (Wide::from_dec64(self).ln() / wide::LN_10).to_dec64()
+    }
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// Returns NaN if the number is not positive, or if it is NaN.
+    pub fn log2(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if self.coefficient() <= 0 {
+            return nan!(NanReason::Invalid, "Dec64::log2", self);
+        }
+
+        (Wide::from_dec64(self).ln() / wide::LN_2).to_dec64()
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// Returns NaN if either number is not positive or is NaN, or if the base is one.
+    pub fn log(self, base: Dec64) -> Dec64 {
+        if self.is_nan() || base.is_nan() {
+            return first_nan(self, base);
+        } else if self.coefficient() <= 0 || base.coefficient() <= 0 {
+            return nan!(NanReason::Invalid, "Dec64::log", self, base);
+        }
+
+        let ln_base = Wide::from_dec64(base).ln();
+        if ln_base.is_zero() {
+            return nan!(NanReason::DivisionByZero, "Dec64::log", self, base);
+        }
+
+        (Wide::from_dec64(self).ln() / ln_base).to_dec64()
+    }
+
+    /// Raises the number to a power.
+    ///
+    /// Integer powers are computed exactly before the result is rounded, others
+    /// as `exp(exponent * ln(self))`. `0^0` is one.
+    ///
+    /// Returns NaN if the result is too large to be represented, if a negative
+    /// number is raised to a non-integer power, if zero is raised to a negative
+    /// power, or if either number is NaN.
+    pub fn pow(self, exponent: Dec64) -> Dec64 {
+        operation!("Dec64::pow", self, exponent);
+
+        if self.is_nan() || exponent.is_nan() {
+            return first_nan(self, exponent);
+        } else if exponent.is_zero() {
+            return ONE;
+        } else if self.is_zero() {
+            return if exponent.coefficient() > 0 {
+                ZERO
+            } else {
+                nan!(NanReason::DivisionByZero, "Dec64::pow", self, exponent)
+            };
+        }
+
+        let x = Wide::from_dec64(self);
+        if exponent.is_integer() {
+            if let Some(n) = exponent.as_integer() {
+                return x.powi(n).to_dec64();
+            }
+
+            // Too large to be odd, so the sign of the base no longer matters.
+            return (Wide::from_dec64(exponent) * x.abs().ln()).exp().to_dec64();
+        } else if x.is_negative() {
+            return nan!(NanReason::Invalid, "Dec64::pow", self, exponent);
+        }
+
+        (Wide::from_dec64(exponent) * x.ln()).exp().to_dec64()
+    }
+
+    /// Raises the number to an integer power, by repeated squaring with a
+    /// single rounding at the end. Negative powers divide one by the result.
+    /// `0^0` is one.
+    ///
+    /// Returns NaN if the result is too large to be represented, if zero is
+    /// raised to a negative power, or if the number is NaN.
+    pub fn powi(self, n: i32) -> Dec64 {
+        operation!("Dec64::powi", self, n);
+
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if n == 0 {
+            return ONE;
+        } else if self.is_zero() {
+            return if n > 0 { ZERO } else { nan!(NanReason::DivisionByZero, "Dec64::powi", self, n) };
+        }
+
+        Wide::from_dec64(self).powi(i64::from(n)).to_dec64()
+    }
+
+    /// Checked integer power. Computes `self.powi(n)`, returning `None` if
+    /// the result would be NaN. No NaN is produced on the way, so nothing is
+    /// recorded in the current `Context` for it.
+    pub fn checked_powi(self, n: i32) -> Option<Dec64> {
+        if self.is_nan() || (self.is_zero() && n < 0) {
+            return None;
+        } else if n == 0 || self.is_zero() {
+            return Some(self.powi(n));
+        }
+
+        Wide::from_dec64(self).powi(i64::from(n)).try_to_dec64()
+    }
+}
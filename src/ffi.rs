@@ -0,0 +1,49 @@
+//! The C interface of `dec64.h`, over the functions of the `crockford` module.
+//!
+//! A `Dec64` has the layout of an `i64`, so these take and return `dec64`
+//! as C does. `include/dec64.h` declares them, and `tests/header.rs` checks
+//! that it agrees with the list below.
+
+use super::Dec64;
+use crockford;
+
+macro_rules! export {
+    ( $( fn $name:ident ( $( $parameter:ident : $type:ty ),* ) -> $result:ty; )* ) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $name( $( $parameter: $type ),* ) -> $result {
+                crockford::$name( $( $parameter ),* )
+            }
+        )*
+    }
+}
+
+export! {
+    fn dec64_abs(number: Dec64) -> Dec64;
+    fn dec64_add(augend: Dec64, addend: Dec64) -> Dec64;
+    fn dec64_ceiling(number: Dec64) -> Dec64;
+    fn dec64_coefficient(number: Dec64) -> i64;
+    fn dec64_dec(minuend: Dec64) -> Dec64;
+    fn dec64_divide(dividend: Dec64, divisor: Dec64) -> Dec64;
+    fn dec64_exponent(number: Dec64) -> i64;
+    fn dec64_floor(number: Dec64) -> Dec64;
+    fn dec64_half(dividend: Dec64) -> Dec64;
+    fn dec64_inc(augend: Dec64) -> Dec64;
+    fn dec64_int(number: Dec64) -> Dec64;
+    fn dec64_integer_divide(dividend: Dec64, divisor: Dec64) -> Dec64;
+    fn dec64_is_equal(comparahend: Dec64, comparator: Dec64) -> Dec64;
+    fn dec64_is_false(boolean: Dec64) -> Dec64;
+    fn dec64_is_integer(number: Dec64) -> Dec64;
+    fn dec64_is_less(comparahend: Dec64, comparator: Dec64) -> Dec64;
+    fn dec64_is_nan(number: Dec64) -> Dec64;
+    fn dec64_is_zero(number: Dec64) -> Dec64;
+    fn dec64_modulo(dividend: Dec64, divisor: Dec64) -> Dec64;
+    fn dec64_multiply(multiplicand: Dec64, multiplier: Dec64) -> Dec64;
+    fn dec64_neg(number: Dec64) -> Dec64;
+    fn dec64_new(coefficient: i64, exponent: i64) -> Dec64;
+    fn dec64_normal(number: Dec64) -> Dec64;
+    fn dec64_not(boolean: Dec64) -> Dec64;
+    fn dec64_round(number: Dec64, place: Dec64) -> Dec64;
+    fn dec64_signum(number: Dec64) -> Dec64;
+    fn dec64_subtract(minuend: Dec64, subtrahend: Dec64) -> Dec64;
+}
@@ -0,0 +1,237 @@
+//! Decimal numbers with a fixed exponent, like SQL `DECIMAL(p, s)`.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{Context, Dec64, Inexact, OutOfRange, RoundingMode, MAX_COEFFICIENT, MIN_COEFFICIENT};
+
+/// A `Dec64` that always has the exponent `SCALE`, so `Fixed<-2>` counts in
+/// hundredths. It has the same bit layout as the `Dec64` it wraps. `SCALE`
+/// can not be `-128`, which is the exponent of NaN: constructing a
+/// `Fixed<-128>` fails to compile.
+///
+/// The arithmetic operators round in the rounding mode of the current thread's
+/// `Context` and panic when the result does not fit, like integer overflow.
+/// The `checked_*` methods take a rounding mode and return `None` instead.
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed<const SCALE: i8> {
+    value: Dec64,
+}
+
+impl<const SCALE: i8> Fixed<SCALE> {
+    /// Evaluated by every constructor, so that a NaN scale fails to compile.
+    const VALID: () = assert!(SCALE != -128, "the scale of a Fixed can not be -128, the exponent of NaN");
+
+    /// Rescales a `Dec64` to `SCALE`, rounding it in the given mode.
+    ///
+    /// Returns `Err(OutOfRange)` if the rescaled coefficient is out of range, or
+    /// if the number is NaN.
+    pub fn new(value: Dec64, mode: RoundingMode) -> Result<Self, OutOfRange> {
+        let () = Self::VALID;
+
+        value.quantize(SCALE, mode).map(|value| Fixed { value })
+    }
+
+    /// Constructs a number from its coefficient, in units of `10^SCALE`.
+    ///
+    /// Returns `None` if the coefficient is out of range.
+    pub fn from_coefficient(coefficient: i64) -> Option<Self> {
+        Self::from_i128(coefficient as i128)
+    }
+
+    /// Returns the coefficient, in units of `10^SCALE`.
+    #[inline]
+    pub fn coefficient(self) -> i64 {
+        self.value.coefficient()
+    }
+
+    /// Returns the underlying `Dec64`, whose exponent is always `SCALE`.
+    #[inline]
+    pub fn to_dec64(self) -> Dec64 {
+        self.value
+    }
+
+    /// Checked addition. Returns `None` if the result does not fit.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::from_i128(self.coefficient() as i128 + other.coefficient() as i128)
+    }
+
+    /// Checked subtraction. Returns `None` if the result does not fit.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::from_i128(self.coefficient() as i128 - other.coefficient() as i128)
+    }
+
+    /// Checked multiplication, rounding the exact product in the given mode.
+    /// Returns `None` if the result does not fit.
+    pub fn checked_mul(self, other: Self, mode: RoundingMode) -> Option<Self> {
+        // The product has the exponent `2 * SCALE`, so it is brought back by `-SCALE`.
+        let product = self.coefficient() as i128 * other.coefficient() as i128;
+        let coefficient = if SCALE < 0 {
+            // A product is below 10^34, so a larger divisor rounds it the same way.
+            mode.divide(product, 10i128.pow((-(SCALE as i32)).min(34) as u32))
+        } else {
+            product.checked_mul(10i128.checked_pow(SCALE as u32)?)?
+        };
+
+        Self::from_i128(coefficient)
+    }
+
+    /// Checked division, rounding the exact quotient in the given mode.
+    /// Returns `None` if `other` is zero, or if the result does not fit.
+    pub fn checked_div(self, other: Self, mode: RoundingMode) -> Option<Self> {
+        let divisor = other.coefficient() as i128;
+        if divisor == 0 {
+            return None;
+        } else if self.coefficient() == 0 {
+            return Some(self);
+        }
+
+        // The quotient of the coefficients has the exponent 0, so it is brought
+        // to `SCALE` by scaling up either the dividend or the divisor.
+        let (dividend, divisor) = if SCALE < 0 {
+            let power = 10i128.checked_pow(-(SCALE as i32) as u32)?;
+
+            ((self.coefficient() as i128).checked_mul(power)?, divisor)
+        } else {
+            // A coefficient is below 10^17, so a larger divisor rounds it the same way.
+            (self.coefficient() as i128, divisor * 10i128.pow((SCALE as u32).min(18)))
+        };
+
+        // Rounding expects a positive divisor.
+        let (dividend, divisor) = if divisor < 0 { (-dividend, -divisor) } else { (dividend, divisor) };
+
+        Self::from_i128(mode.divide(dividend, divisor))
+    }
+
+    /// Checked negation. Returns `None` for the smallest coefficient, whose
+    /// negation does not fit.
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::from_i128(-(self.coefficient() as i128))
+    }
+
+    fn from_i128(coefficient: i128) -> Option<Self> {
+        let () = Self::VALID;
+
+        if coefficient < MIN_COEFFICIENT as i128 || coefficient > MAX_COEFFICIENT as i128 {
+            return None;
+        }
+
+        Some(Fixed { value: Dec64::from_parts(coefficient as i64, SCALE) })
+    }
+}
+
+impl<const SCALE: i8> Default for Fixed<SCALE> {
+    fn default() -> Self {
+        let () = Self::VALID;
+
+        Fixed { value: Dec64::from_parts(0, SCALE) }
+    }
+}
+
+impl<const SCALE: i8> From<Fixed<SCALE>> for Dec64 {
+    fn from(fixed: Fixed<SCALE>) -> Dec64 {
+        fixed.value
+    }
+}
+
+/// Succeeds only if the number can be rescaled to `SCALE` without rounding.
+impl<const SCALE: i8> TryFrom<Dec64> for Fixed<SCALE> {
+    type Error = Inexact;
+
+    fn try_from(value: Dec64) -> Result<Self, Inexact> {
+        let fixed = Self::new(value, RoundingMode::TowardZero).map_err(|_| Inexact)?;
+        if fixed.value != value {
+            return Err(Inexact);
+        }
+
+        Ok(fixed)
+    }
+}
+
+impl<const SCALE: i8> PartialEq for Fixed<SCALE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficient() == other.coefficient()
+    }
+}
+
+impl<const SCALE: i8> Eq for Fixed<SCALE> {}
+
+impl<const SCALE: i8> PartialOrd for Fixed<SCALE> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const SCALE: i8> Ord for Fixed<SCALE> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.coefficient().cmp(&other.coefficient())
+    }
+}
+
+impl<const SCALE: i8> Hash for Fixed<SCALE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coefficient().hash(state);
+    }
+}
+
+/// # Panics
+///
+/// Panics if the sum does not fit. Use `checked_add` to get `None` instead.
+impl<const SCALE: i8> Add for Fixed<SCALE> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("Fixed addition overflowed")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the difference does not fit. Use `checked_sub` to get `None` instead.
+impl<const SCALE: i8> Sub for Fixed<SCALE> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("Fixed subtraction overflowed")
+    }
+}
+
+/// Rounds in the current thread's `Context`.
+///
+/// # Panics
+///
+/// Panics if the product does not fit. Use `checked_mul` to get `None` instead.
+impl<const SCALE: i8> Mul for Fixed<SCALE> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other, Context::current().rounding()).expect("Fixed multiplication overflowed")
+    }
+}
+
+/// Rounds in the current thread's `Context`.
+///
+/// # Panics
+///
+/// Panics if `other` is zero, or if the quotient does not fit. Use
+/// `checked_div` to get `None` instead.
+impl<const SCALE: i8> Div for Fixed<SCALE> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(other, Context::current().rounding()).expect("Fixed division by zero or overflowed")
+    }
+}
+
+/// # Panics
+///
+/// Panics if the number has the smallest coefficient, whose negation does not fit. Use `checked_neg` to get `None` instead.
+impl<const SCALE: i8> Neg for Fixed<SCALE> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("Fixed negation overflowed")
+    }
+}
@@ -0,0 +1,61 @@
+//! Fused operations, which round only once.
+
+use super::{first_nan, quotient, sum, Dec64};
+use reason::NanReason;
+use context::round_current;
+
+impl Dec64 {
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if any of the numbers is NaN.
+    pub fn mul_add(self, a: Dec64, b: Dec64) -> Dec64 {
+        operation!("Dec64::mul_add", self, a, b);
+
+        if self.is_nan() || a.is_nan() || b.is_nan() {
+            return first_nan(self, first_nan(a, b));
+        }
+
+        // The product of two coefficients takes at most 111 bits.
+        let product = (
+            self.coefficient() as i128 * a.coefficient() as i128,
+            self.exponent() as i32 + a.exponent() as i32,
+        );
+        if product.0 == 0 {
+            return b;
+        }
+
+        // Leave a bit of headroom so that the sum cannot overflow.
+        let (sum, exponent, sticky) = sum(product, (b.coefficient() as i128, b.exponent() as i32), i128::MAX / 2);
+
+        round_current(sum, exponent, sticky)
+    }
+
+    /// Computes `(self * a) / b` with only one rounding, keeping the product
+    /// in 128 bits.
+    ///
+    /// Returns NaN, raising `division_by_zero`, if `b` is zero. Raises `invalid`
+    /// instead if the product is zero too, as `Context::div` does for `0 / 0`.
+    /// Also returns NaN if the result is too large to be represented, or if any
+    /// of the numbers is NaN.
+    pub fn mul_div(self, a: Dec64, b: Dec64) -> Dec64 {
+        operation!("Dec64::mul_div", self, a, b);
+
+        if self.is_nan() || a.is_nan() || b.is_nan() {
+            return first_nan(self, first_nan(a, b));
+        } else if b.is_zero() {
+            return if self.is_zero() || a.is_zero() {
+                nan!(NanReason::Invalid, "Dec64::mul_div", self, a, b)
+            } else {
+                nan!(NanReason::DivisionByZero, "Dec64::mul_div", self, a, b)
+            };
+        }
+
+        let (quotient, exponent, sticky) = quotient(
+            self.coefficient() as i128 * a.coefficient() as i128,
+            b.coefficient() as i128,
+            self.exponent() as i32 + a.exponent() as i32 - b.exponent() as i32,
+        );
+
+        round_current(quotient, exponent, sticky)
+    }
+}
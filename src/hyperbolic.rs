@@ -0,0 +1,138 @@
+//! Hyperbolic functions and their inverses.
+//!
+//! Close to zero these avoid differences of nearly equal exponentials, which
+//! would cancel out most of the digits of the result.
+
+use super::Dec64;
+use reason::{pass_nan, NanReason};
+use wide::{Wide, ONE};
+
+/// Taylor series of the hyperbolic sine, for arguments below one.
+fn sinh_series(x: Wide) -> Wide {
+    let x2 = x * x;
+    let mut sum = x;
+    let mut term = x;
+    let mut n = 1;
+    loop {
+        term = term * x2 / Wide::from_i64((n + 1) * (n + 2));
+        n += 2;
+        if term.is_negligible(sum) {
+            return sum;
+        }
+        sum = sum + term;
+    }
+}
+
+impl Dec64 {
+    /// Returns the hyperbolic sine of a number.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if the number is NaN.
+    pub fn sinh(self) -> Dec64 {
+        operation!("Dec64::sinh", self);
+
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let x = Wide::from_dec64(self);
+        let sinh = if x.abs() < ONE {
+            sinh_series(x)
+        } else {
+            // sinh(x) = (e^x - e^-x) / 2
+            let exp = x.exp();
+            (exp - ONE / exp).half()
+        };
+
+        sinh.to_dec64()
+    }
+
+    /// Returns the hyperbolic cosine of a number.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if the number is NaN.
+    pub fn cosh(self) -> Dec64 {
+        operation!("Dec64::cosh", self);
+
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        // cosh(x) = (e^x + e^-x) / 2
+        let exp = Wide::from_dec64(self).exp();
+
+        (exp + ONE / exp).half().to_dec64()
+    }
+
+    /// Returns the hyperbolic tangent of a number.
+    ///
+    /// Returns NaN if the number is NaN.
+    pub fn tanh(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let x = Wide::from_dec64(self);
+        let tanh = if x.abs() < ONE {
+            let exp = x.exp();
+            sinh_series(x) / (exp + ONE / exp).half()
+        } else {
+            // tanh(x) = (e^2x - 1) / (e^2x + 1)
+            let exp = (x + x).exp();
+            (exp - ONE) / (exp + ONE)
+        };
+
+        tanh.to_dec64()
+    }
+
+    /// Returns the inverse hyperbolic sine of a number.
+    ///
+    /// Returns NaN if the number is NaN.
+    pub fn asinh(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        // asinh(x) = ln(1 + |x| + x² / (1 + sqrt(1 + x²))), with the sign of x
+        let x = Wide::from_dec64(self);
+        let x2 = x * x;
+        let asinh = (x.abs() + x2 / (ONE + (ONE + x2).sqrt())).ln_1p();
+        let asinh = if x.is_negative() { -asinh } else { asinh };
+
+        asinh.to_dec64()
+    }
+
+    /// Returns the inverse hyperbolic cosine of a number.
+    ///
+    /// Returns NaN if the number is less than one, or if it is NaN.
+    pub fn acosh(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let x = Wide::from_dec64(self);
+        if x < ONE {
+            return nan!(NanReason::Invalid, "Dec64::acosh", self);
+        }
+
+        // acosh(x) = ln(1 + (x - 1) + sqrt((x - 1) * (x + 1)))
+        let x_1 = x - ONE;
+
+        (x_1 + (x_1 * (x + ONE)).sqrt()).ln_1p().to_dec64()
+    }
+
+    /// Returns the inverse hyperbolic tangent of a number.
+    ///
+    /// Returns NaN if the number is outside of the range `(-1, 1)`, or if it is NaN.
+    pub fn atanh(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let x = Wide::from_dec64(self);
+        if x.abs() >= ONE {
+            return nan!(NanReason::Invalid, "Dec64::atanh", self);
+        }
+
+        // atanh(x) = ln(1 + 2x / (1 - x)) / 2
+        ((x + x) / (ONE - x)).ln_1p().half().to_dec64()
+    }
+}
@@ -0,0 +1,389 @@
+//! Number semantics of JavaScript, for engines that use `Dec64` as their
+//! number type.
+//!
+//! The formatting functions follow ECMAScript's `Number.prototype` methods.
+//! Where those work with the shortest digits that identify a double, these
+//! work with the digits of the coefficient, without trailing zeros, which is
+//! exact. Where ECMAScript throws a `RangeError`, these return `None`.
+//!
+//! The bitwise functions convert their operands with `to_int32` or `to_uint32`
+//! and return the integer result as a `Dec64`, like the JavaScript operators.
+
+use super::Dec64;
+
+/// The most digits `to_fixed`, `to_precision` and `to_exponential` accept.
+const MAX_DIGITS: u32 = 100;
+
+/// The digits of a number without its sign, as `0.d1d2…dk × 10^point`.
+/// There are no leading or trailing zeros, and no digits at all for zero.
+#[derive(Clone, Debug)]
+struct Digits {
+    digits: Vec<u8>,
+    point: i32,
+}
+
+impl Digits {
+    fn new(number: Dec64) -> Digits {
+        let mut digits: Vec<u8> = number.coefficient().unsigned_abs().to_string().bytes().map(|b| b - b'0').collect();
+        let point = digits.len() as i32 + number.exponent() as i32;
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        Digits { digits, point }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// The digit at the place `10^place`.
+    fn at(&self, place: i32) -> u8 {
+        let index = self.point - 1 - place;
+        if index >= 0 && (index as usize) < self.digits.len() {
+            self.digits[index as usize]
+        } else {
+            0
+        }
+    }
+
+    /// Rounds to `keep` significant digits, ties away from zero.
+    fn round(mut self, keep: i32) -> Digits {
+        if keep < 0 || self.is_zero() {
+            return Digits { digits: Vec::new(), point: 0 };
+        } else if self.digits.len() as i32 <= keep {
+            return self;
+        }
+
+        let up = self.digits[keep as usize] >= 5;
+        self.digits.truncate(keep as usize);
+        if up {
+            // Carry into the kept digits, which may all have been nines.
+            loop {
+                match self.digits.pop() {
+                    Some(9) => continue,
+                    Some(digit) => {
+                        self.digits.push(digit + 1);
+                        break;
+                    },
+                    None => {
+                        self.digits.push(1);
+                        self.point += 1;
+                        break;
+                    },
+                }
+            }
+        }
+
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        if self.is_zero() {
+            self.point = 0;
+        }
+
+        self
+    }
+
+    /// Writes the digits from the place `10^from` down to `10^to`.
+    fn write_places(&self, out: &mut String, from: i32, to: i32) {
+        for place in (to..=from).rev() {
+            out.push((b'0' + self.at(place)) as char);
+        }
+    }
+
+    /// Writes `count` significant digits with a point after the first one,
+    /// and the exponent, as `d.ddde+n`.
+    fn write_exponential(&self, out: &mut String, count: i32) {
+        let exponent = if self.is_zero() { 0 } else { self.point - 1 };
+
+        self.write_places(out, exponent, exponent);
+        if count > 1 {
+            out.push('.');
+            self.write_places(out, exponent - 1, exponent - count + 1);
+        }
+        out.push('e');
+        out.push(if exponent < 0 { '-' } else { '+' });
+        out.push_str(&exponent.abs().to_string());
+    }
+}
+
+/// Writes the sign of a number, returning the digits of its magnitude.
+fn sign(number: Dec64, out: &mut String) -> Digits {
+    if number.coefficient() < 0 {
+        out.push('-');
+    }
+
+    Digits::new(number)
+}
+
+/// Converts a number to a string, like `Number.prototype.toString()`.
+pub fn to_string(number: Dec64) -> String {
+    if number.is_nan() {
+        return "NaN".to_string();
+    } else if number.is_zero() {
+        return "0".to_string();
+    }
+
+    let mut out = String::new();
+    let digits = sign(number, &mut out);
+    let (k, n) = (digits.digits.len() as i32, digits.point);
+
+    if k <= n && n <= 21 {
+        // An integer, padded with zeros.
+        digits.write_places(&mut out, n - 1, 0);
+    } else if 0 < n && n <= 21 {
+        digits.write_places(&mut out, n - 1, 0);
+        out.push('.');
+        digits.write_places(&mut out, -1, n - k);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        digits.write_places(&mut out, -1, n - k);
+    } else {
+        digits.write_exponential(&mut out, k);
+    }
+
+    out
+}
+
+/// Formats a number with `fraction_digits` digits after the point, like
+/// `Number.prototype.toFixed()`. Numbers of `1e21` or more in magnitude are
+/// formatted by `to_string`.
+///
+/// Returns `None` if `fraction_digits` is greater than 100.
+pub fn to_fixed(number: Dec64, fraction_digits: u32) -> Option<String> {
+    if fraction_digits > MAX_DIGITS {
+        return None;
+    } else if number.is_nan() {
+        return Some("NaN".to_string());
+    }
+
+    let mut out = String::new();
+    let fraction_digits = fraction_digits as i32;
+    let digits = sign(number, &mut out);
+    if digits.point > 21 {
+        return Some(to_string(number));
+    }
+
+    // A negative number keeps its sign even when it rounds to zero.
+    let keep = digits.point + fraction_digits;
+    let digits = digits.round(keep);
+
+    digits.write_places(&mut out, (digits.point - 1).max(0), 0);
+    if fraction_digits > 0 {
+        out.push('.');
+        digits.write_places(&mut out, -1, -fraction_digits);
+    }
+
+    Some(out)
+}
+
+/// Formats a number in exponential notation, like `Number.prototype.toExponential()`.
+/// With `None`, as many digits are used as the number needs.
+///
+/// Returns `None` if `fraction_digits` is greater than 100.
+pub fn to_exponential(number: Dec64, fraction_digits: Option<u32>) -> Option<String> {
+    if fraction_digits.is_some_and(|digits| digits > MAX_DIGITS) {
+        return None;
+    } else if number.is_nan() {
+        return Some("NaN".to_string());
+    }
+
+    let mut out = String::new();
+    let digits = sign(number, &mut out);
+    let count = match fraction_digits {
+        Some(fraction_digits) => fraction_digits as i32 + 1,
+        None => (digits.digits.len() as i32).max(1),
+    };
+
+    digits.round(count).write_exponential(&mut out, count);
+
+    Some(out)
+}
+
+/// Formats a number with `precision` significant digits, like
+/// `Number.prototype.toPrecision()`. With `None`, this is `to_string`.
+///
+/// Returns `None` if `precision` is not between 1 and 100.
+pub fn to_precision(number: Dec64, precision: Option<u32>) -> Option<String> {
+    let precision = match precision {
+        Some(precision) if precision == 0 || precision > MAX_DIGITS => return None,
+        Some(precision) => precision as i32,
+        None => return Some(to_string(number)),
+    };
+    if number.is_nan() {
+        return Some("NaN".to_string());
+    }
+
+    let mut out = String::new();
+    let digits = sign(number, &mut out).round(precision);
+    let exponent = if digits.is_zero() { 0 } else { digits.point - 1 };
+
+    if exponent < -6 || exponent >= precision {
+        digits.write_exponential(&mut out, precision);
+    } else {
+        digits.write_places(&mut out, exponent.max(0), 0);
+        if exponent < precision - 1 {
+            out.push('.');
+            digits.write_places(&mut out, -1, exponent - precision + 1);
+        }
+    }
+
+    Some(out)
+}
+
+/// Converts a number to a string in the given radix, like
+/// `Number.prototype.toString(radix)`, with lowercase letters for digits above 9.
+///
+/// A fraction that does not end in the radix is cut off once it has as many
+/// significant digits as a coefficient can tell apart, and rounded.
+///
+/// Returns `None` if `radix` is not between 2 and 36.
+pub fn to_string_radix(number: Dec64, radix: u32) -> Option<String> {
+    if !(2..=36).contains(&radix) {
+        return None;
+    } else if radix == 10 || number.is_nan() || number.is_zero() {
+        return Some(to_string(number));
+    }
+
+    let mut out = String::new();
+    let digits = sign(number, &mut out);
+
+    // The integer part, most significant digit first, is divided by the radix
+    // for as long as it is not zero. The remainders are its digits.
+    let mut integer: Vec<u32> = (0..digits.point).rev().map(|place| digits.at(place) as u32).collect();
+    let mut result = Vec::new();
+    while !integer.is_empty() {
+        let mut remainder = 0;
+        for digit in integer.iter_mut() {
+            let value = remainder * 10 + *digit;
+            *digit = value / radix;
+            remainder = value % radix;
+        }
+        result.push(remainder);
+
+        let leading = integer.iter().take_while(|&&digit| digit == 0).count();
+        integer.drain(..leading);
+    }
+    result.reverse();
+    let mut integer_length = result.len();
+
+    // The fraction, least significant digit first, is multiplied by the radix.
+    // What carries out of it is the next digit.
+    let last_place = digits.point - digits.digits.len() as i32;
+    let mut fraction: Vec<u32> = (last_place..digits.point.min(0)).map(|place| digits.at(place) as u32).collect();
+    let limit = (17.0 * 10f64.ln() / (radix as f64).ln()).ceil() as usize;
+    let mut significant = result.len();
+    while fraction.iter().any(|&digit| digit != 0) && significant <= limit {
+        let mut carry = 0;
+        for digit in fraction.iter_mut() {
+            let value = *digit * radix + carry;
+            *digit = value % 10;
+            carry = value / 10;
+        }
+        result.push(carry);
+        if significant > 0 || carry != 0 {
+            significant += 1;
+        }
+    }
+
+    // Past the limit, the last digit only decides the rounding.
+    if significant > limit && result.len() > integer_length {
+        let last = result.pop().unwrap_or(0);
+        if last * 2 >= radix {
+            let carried = result.iter_mut().rev().all(|digit| {
+                *digit += 1;
+                if *digit < radix {
+                    return false;
+                }
+                *digit = 0;
+                true
+            });
+            if carried {
+                result.insert(0, 1);
+                integer_length += 1;
+            }
+        }
+    }
+    while result.len() > integer_length && result.last() == Some(&0) {
+        result.pop();
+    }
+
+    let digit = |digit: &u32| std::char::from_digit(*digit, radix).unwrap_or('0');
+    if integer_length == 0 {
+        out.push('0');
+    }
+    out.extend(result[..integer_length].iter().map(digit));
+    if result.len() > integer_length {
+        out.push('.');
+        out.extend(result[integer_length..].iter().map(digit));
+    }
+
+    Some(out)
+}
+
+/// Converts a number to a signed 32-bit integer, like ECMAScript's `ToInt32`:
+/// the fraction is dropped, and the integer wraps around modulo `2^32`.
+/// NaN gives zero.
+pub fn to_int32(number: Dec64) -> i32 {
+    to_uint32(number) as i32
+}
+
+/// Converts a number to an unsigned 32-bit integer, like ECMAScript's `ToUint32`:
+/// the fraction is dropped, and the integer wraps around modulo `2^32`.
+/// NaN gives zero.
+pub fn to_uint32(number: Dec64) -> u32 {
+    if number.is_nan() {
+        return 0;
+    }
+
+    let coefficient = number.coefficient();
+    let exponent = number.exponent();
+    if exponent < 0 {
+        // A coefficient has fewer than 18 digits.
+        let divisor = 10i64.checked_pow(-(exponent as i32) as u32).unwrap_or(i64::MAX);
+
+        return (coefficient / divisor) as u32;
+    }
+
+    // Multiplying modulo 2^32 keeps the low 32 bits exact.
+    (0..exponent).fold(coefficient as u32, |integer, _| integer.wrapping_mul(10))
+}
+
+/// The `&` operator.
+pub fn bit_and(a: Dec64, b: Dec64) -> Dec64 {
+    Dec64::from(to_int32(a) & to_int32(b))
+}
+
+/// The `|` operator.
+pub fn bit_or(a: Dec64, b: Dec64) -> Dec64 {
+    Dec64::from(to_int32(a) | to_int32(b))
+}
+
+/// The `^` operator.
+pub fn bit_xor(a: Dec64, b: Dec64) -> Dec64 {
+    Dec64::from(to_int32(a) ^ to_int32(b))
+}
+
+/// The `~` operator.
+pub fn bit_not(a: Dec64) -> Dec64 {
+    Dec64::from(!to_int32(a))
+}
+
+/// The `<<` operator. Only the low five bits of the shift count are used.
+pub fn shl(a: Dec64, b: Dec64) -> Dec64 {
+    Dec64::from(to_int32(a).wrapping_shl(to_uint32(b)))
+}
+
+/// The `>>` operator, which keeps the sign. Only the low five bits of the
+/// shift count are used.
+pub fn shr(a: Dec64, b: Dec64) -> Dec64 {
+    Dec64::from(to_int32(a).wrapping_shr(to_uint32(b)))
+}
+
+/// The `>>>` operator, which shifts in zeros. Only the low five bits of the
+/// shift count are used.
+pub fn ushr(a: Dec64, b: Dec64) -> Dec64 {
+    Dec64::from(to_uint32(a).wrapping_shr(to_uint32(b)))
+}
@@ -1,11 +1,38 @@
 #[macro_use] mod macros;
+mod cmp;
+mod context;
+pub mod crockford;
 mod diyfp;
+mod exp;
+#[cfg(feature = "c-api")]
+mod ffi;
+mod fixed;
 mod fmt;
+mod fused;
 mod grisu2;
+mod hyperbolic;
+pub mod js;
 pub mod more_consts;
+pub mod nanbox;
+mod next;
 mod ops;
+mod reason;
+mod roots;
+mod round;
+mod scaled;
+mod trig;
+mod wide;
 mod write;
 
+pub use context::{Context, Flags};
+pub use fixed::Fixed;
+pub use reason::NanReason;
+pub use round::{Inexact, OutOfRange, RoundingMode};
+
+use std::cmp::Ordering;
+
+use reason::pass_nan;
+
 /// Minimum value of DEC64 coefficient.
 pub const MIN_COEFFICIENT: i64 = -36_028_797_018_963_968;
 /// Maximum value of DEC64 coefficient.
@@ -116,7 +143,10 @@ const POWERS_10: [u64; 20] = [
 ];
 
 /// Struct holding DEC64 value.
+///
+/// It has the same layout as an `i64`, which is how C passes a `dec64`.
 #[derive(Clone, Copy, Default)]
+#[repr(transparent)]
 pub struct Dec64 {
     value: i64
 }
@@ -137,6 +167,8 @@ impl Dec64 {
     /// This will produce only standard (coefficient zeroed) kind of zero and NaN.
     #[inline]
     pub fn new(coefficient: i64, exponent: i16) -> Self {
+        operation!("Dec64::new", coefficient, exponent);
+
         Self::pack(coefficient, exponent as i32)
     }
 
@@ -145,98 +177,27 @@ impl Dec64 {
     /// Numbers that are too huge to be contained in this format become NaN.
     ///
     /// Numbers that are too tiny to be contained in this format become zero.
-    pub fn pack(mut coefficient: i64, mut exponent: i32) -> Self {
+    ///
+    /// Digits that do not fit are rounded in the rounding mode of the current
+    /// thread's `Context`, whose flags record any rounding, overflow or underflow.
+    pub fn pack(coefficient: i64, exponent: i32) -> Self {
         if coefficient == 0 {
             // If the coefficient is zero, also zero the exponent.
             return ZERO;
         }
 
-        // Is the exponent within supported range?
-        if MIN_EXP <= exponent && exponent <= MAX_EXP {
-            // Is the coefficient within supported range?
-            if MIN_COEFFICIENT <= coefficient && coefficient <= MAX_COEFFICIENT {
-                // Coefficient and exponent are OK.
-                return dec64_parts!(coefficient, exponent);
-            } else {
-                // The coefficient is too long.
-                // Add one to the exponent and Divide the coefficient by 10.
-                loop {
-                    exponent += 1;
-                    if exponent > MAX_EXP {
-                        // We cannot fit this number.
-                        return NAN;
-                    }
-
-                    let rem = coefficient % 10;
-                    coefficient /= 10;
-                    // Reminder of coefficient division for rounding decision.
-                    // Does it fit now?
-                    if MIN_COEFFICIENT <= coefficient && coefficient <= MAX_COEFFICIENT {
-                        // Examine the remainder to determine if the coefficient should be rounded up
-                        // or down. We will shift before adding in the rounding bit to get the cheap
-                        // overflow check. If rounding does not cause overflow, pack up and get out.
-                        let round_add = if rem <= -5 {
-                            -1 << 8
-                        } else if rem >= 5 {
-                             1 << 8
-                        } else {
-                             0
-                        };
-
-                        // If rounding caused the coefficient to overflow, then go one more time
-                        // through the loop. Otherwise return the dec64.
-                        // This is extremely unlikely.
-                        let (ret_value, overflow) =  (coefficient << 8).overflowing_add(round_add);
-                        if !overflow {
-                            if coefficient == 0 {
-                                // If the coefficient is zero, also zero the exponent.
-                                return ZERO;
-                            }
-                            return Dec64 { value: ret_value | ((exponent as u8) as i64) };
-                        }
-                    }
-                }
-            }
-        } else if exponent > MAX_EXP {
-            // The exponent is too big. We can attempt to reduce it by scaling back.
-            // This can decrease it in a small set of cases.
-            loop {
-                // try multiplying the coefficient by 10
-                let (coefficient_mul_10, overflow) = coefficient.overflowing_mul(10);
-                if overflow || coefficient_mul_10 < MIN_COEFFICIENT || MAX_COEFFICIENT < coefficient_mul_10 {
-                    // We failed to salvage.
-                    return NAN;
-                }
-                coefficient = coefficient_mul_10;
-
-                // decrement the exponent
-                exponent -= 1;
-                if exponent <= MAX_EXP {
-                    return dec64_parts!(coefficient, exponent);
-                }
-            }
-        } else if exponent < MIN_EXP {
-            // The exponent is too small. We can attempt to increase it by scaling forward.
-            // This can increase it in a small set of cases.
-            loop {
-                let (coefficient_div_10, overflow) = coefficient.overflowing_div(10);
-                if overflow || coefficient_div_10 == 0 {
-                    // Value is too small to salvage.
-                    return ZERO;
-                }
-                coefficient = coefficient_div_10;
-
-                // increment the exponent
-                exponent += 1;
-                if exponent >= MIN_EXP {
-                    return dec64_parts!(coefficient, exponent);
-                }
-            }
+        // Are the exponent and coefficient within supported range?
+        if (MIN_EXP..=MAX_EXP).contains(&exponent) && (MIN_COEFFICIENT..=MAX_COEFFICIENT).contains(&coefficient) {
+            return dec64_parts!(coefficient, exponent);
         }
 
-        // We should've accounted for all cases.
-        // If we ever reach here then there's a BUG in the implementation.
-        unreachable!("Dec64::pack(): BUG");
+        Self::pack_i128(coefficient as i128, exponent)
+    }
+
+    /// Packs a coefficient that may not fit an `i64`, rounding it in the
+    /// current thread's `Context`.
+    fn pack_i128(coefficient: i128, exponent: i32) -> Self {
+        context::round_current(coefficient, exponent, false)
     }
 
     /// Returns the DEC64 coefficient.
@@ -262,6 +223,35 @@ impl Dec64 {
         self.exponent() == -128
     }
 
+    /// Constructs a NaN carrying `payload` in its coefficient, such as a code
+    /// telling why a value is missing. A payload of zero gives `NAN`.
+    ///
+    /// Returns `NAN` if the payload does not fit between `MIN_COEFFICIENT`
+    /// and `MAX_COEFFICIENT`.
+    ///
+    /// # Propagation
+    ///
+    /// An operation given a NaN returns that NaN unchanged, payload and all.
+    /// Given several, it returns the first one, so `a + b` passes on the payload
+    /// of `a` if both are NaN. NaNs that an operation produces by itself, on
+    /// overflow or outside of its domain, are `NAN`, unless the `nan-reason`
+    /// feature encodes a `NanReason` in them. `normalize` turns every NaN into
+    /// `NAN`.
+    pub fn nan_with_payload(payload: i64) -> Dec64 {
+        if !(MIN_COEFFICIENT..=MAX_COEFFICIENT).contains(&payload) {
+            return NAN;
+        }
+
+        dec64_parts!(payload, -128_i8)
+    }
+
+    /// Returns the payload of a NaN, which is its coefficient, or `None` if
+    /// the number is not NaN. `NAN` has the payload zero.
+    #[inline]
+    pub fn nan_payload(self) -> Option<i64> {
+        if self.is_nan() { Some(self.coefficient()) } else { None }
+    }
+
     /// Returns `true` if DEC64 is zero and `false` otherwise.
     ///
     /// DEC64 zeros have coefficient value of 0 and any non-NaN exponent.
@@ -292,6 +282,192 @@ impl Dec64 {
 
         false
     }
+
+    /// Returns the integer value of the number if `is_integer()` is `true` and
+    /// it fits an `i64`, and `None` otherwise.
+    pub fn as_integer(self) -> Option<i64> {
+        if !self.is_integer() {
+            return None;
+        }
+
+        let exponent = self.exponent();
+        if exponent < 0 {
+            Some(self.coefficient() / POWERS_10[-exponent as usize] as i64)
+        } else if self.coefficient() == 0 {
+            Some(0)
+        } else {
+            self.coefficient().checked_mul(10i64.checked_pow(exponent as u32)?)
+        }
+    }
+
+    /// Splits the number into its integer and fractional parts, like C `modf`.
+    /// Both parts have the sign of the number, and their sum is the number.
+    ///
+    /// Returns NaNs if the number is NaN.
+    pub fn split_integer_fraction(self) -> (Dec64, Dec64) {
+        if self.is_nan() {
+            return (pass_nan(self), pass_nan(self));
+        }
+
+        let exponent = self.exponent();
+        if exponent >= 0 {
+            return (self, ZERO);
+        } else if exponent <= -17 {
+            // The coefficient has at most 17 digits, so it is all fraction.
+            return (ZERO, self);
+        }
+
+        let power = POWERS_10[-exponent as usize] as i64;
+        let coefficient = self.coefficient();
+
+        (Self::pack(coefficient / power, 0), Self::pack(coefficient % power, exponent as i32))
+    }
+
+    /// Returns the number of digits in the coefficient, or zero if the coefficient
+    /// is zero. Trailing zeros count too, unless the number is normalized first.
+    ///
+    /// Returns zero if the number is NaN.
+    pub fn significant_digits(self) -> u32 {
+        if self.is_nan() {
+            return 0;
+        }
+
+        let coefficient = self.coefficient().unsigned_abs();
+
+        POWERS_10.iter().take_while(|&&power| power <= coefficient).count() as u32
+    }
+
+    /// Returns the number of trailing zeros in the coefficient, or zero if the
+    /// coefficient is zero.
+    ///
+    /// Returns zero if the number is NaN.
+    pub fn trailing_zeros(self) -> u32 {
+        let mut coefficient = self.coefficient();
+        if self.is_nan() || coefficient == 0 {
+            return 0;
+        }
+
+        let mut zeros = 0;
+        while coefficient % 10 == 0 {
+            coefficient /= 10;
+            zeros += 1;
+        }
+
+        zeros
+    }
+
+    /// Returns the canonical representation of the number.
+    ///
+    /// Trailing zeros are removed from the coefficient while the exponent is raised,
+    /// so `1200e-2` becomes `12e0`. All zeros become `ZERO` and all NaNs become `NAN`.
+    pub fn normalize(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+
+        let mut coefficient = self.coefficient();
+        if coefficient == 0 {
+            return ZERO;
+        }
+
+        let mut exponent = self.exponent();
+        while coefficient % 10 == 0 && (exponent as i32) < MAX_EXP {
+            coefficient /= 10;
+            exponent += 1;
+        }
+
+        dec64_parts!(coefficient, exponent)
+    }
+
+    /// Returns `true` if the number is in its canonical representation, that is
+    /// if `normalize()` would return it unchanged, and `false` otherwise.
+    pub fn is_canonical(self) -> bool {
+        self.value == self.normalize().value
+    }
+
+    /// Returns the absolute value of the number.
+    ///
+    /// Returns NaN if the number is NaN.
+    pub fn abs(self) -> Dec64 {
+        operation!("Dec64::abs", self);
+
+        if self.is_nan() {
+            pass_nan(self)
+        } else if self.coefficient() < 0 {
+            // Neg takes care of MIN_COEFFICIENT.
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    /// - `0` if the number is zero
+    /// - the number itself if it is NaN
+    pub fn signum(self) -> Dec64 {
+        if self.is_nan() {
+            pass_nan(self)
+        } else if self.coefficient() > 0 {
+            dec64_parts!(1, 0)
+        } else if self.coefficient() < 0 {
+            dec64_parts!(-1, 0)
+        } else {
+            ZERO
+        }
+    }
+
+    /// Returns `true` if the number is negative and `false` otherwise, including for NaN.
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.coefficient() < 0 && !self.is_nan()
+    }
+
+    /// Returns `true` if the number is positive or zero and `false` otherwise, including for NaN.
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        self.coefficient() >= 0 && !self.is_nan()
+    }
+
+    /// Returns the category the number falls into.
+    pub fn classify(self) -> Dec64Category {
+        if self.is_nan() {
+            if self.coefficient() == 0 {
+                Dec64Category::Nan
+            } else {
+                Dec64Category::NonNormalNan
+            }
+        } else if self.coefficient() == 0 {
+            if self.exponent() == 0 {
+                Dec64Category::Zero
+            } else {
+                Dec64Category::NonNormalZero
+            }
+        } else if self.is_integer() {
+            Dec64Category::Integer
+        } else {
+            Dec64Category::Fraction
+        }
+    }
+}
+
+/// The category a DEC64 falls into, as returned by `Dec64::classify`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dec64Category {
+    /// The standard NaN, with a zero coefficient.
+    Nan,
+    /// The standard zero, with a zero exponent.
+    Zero,
+    /// A nonzero integer.
+    Integer,
+    /// A number with a nonzero fractional part.
+    Fraction,
+    /// A zero with a nonzero exponent, such as `more_consts::ZIP`.
+    NonNormalZero,
+    /// A NaN with a nonzero coefficient, such as `more_consts::NAN_NAN`.
+    NonNormalNan,
 }
 
 impl PartialEq<Dec64> for Dec64 {
@@ -311,20 +487,95 @@ impl PartialEq<Dec64> for Dec64 {
         // If coefficient signs are different, or exponents are equal at this point
         // (after previous values equality test) then te numbers are not equal.
         let vals_xor = self.value ^ other.value;
-        if vals_xor & (SIGN_MASK | EXPONENT_MASK) != 0 {
+        if vals_xor & SIGN_MASK != 0 || vals_xor & EXPONENT_MASK == 0 {
             return false;
         }
 
-        // Do it the hard way by subtracting. Is the difference zero?
-        // TODO: implement Sub.
-        //if (self - other).is_zero() {
-        //    return true;
-        //}
+        // Do it the hard way by bringing them to a common exponent, as ordering
+        // does. Unlike subtracting, this never rounds, so it leaves the flags of
+        // the current context alone.
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
 
-        false
+/// Returns `a` if it is NaN, and `b` otherwise. Of two operands, one of which
+/// is NaN, this picks the NaN to pass on, as by `reason::pass_nan`.
+#[inline]
+fn first_nan(a: Dec64, b: Dec64) -> Dec64 {
+    pass_nan(if a.is_nan() { a } else { b })
+}
+
+/// Brings two `(coefficient, exponent)` pairs to a common exponent, returning
+/// both coefficients, that exponent, and whether any nonzero digits were dropped.
+/// `hi` must have the larger exponent.
+///
+/// First the coefficient of `hi` is multiplied by 10 for as long as its magnitude
+/// stays within `limit`. If the exponents still differ, the coefficient of `lo`
+/// is divided by 10 instead, which loses significance.
+fn align(hi: (i128, i32), lo: (i128, i32), limit: i128) -> (i128, i128, i32, bool) {
+    let (mut hi_coefficient, mut hi_exponent) = hi;
+    let (mut lo_coefficient, mut lo_exponent) = lo;
+
+    while hi_exponent > lo_exponent && hi_coefficient.abs() <= limit / 10 {
+        hi_coefficient *= 10;
+        hi_exponent -= 1;
+    }
+
+    // That is the heartbreak of floating point.
+    let mut sticky = false;
+    while hi_exponent > lo_exponent && lo_coefficient != 0 {
+        sticky |= lo_coefficient % 10 != 0;
+        lo_coefficient /= 10;
+        lo_exponent += 1;
+    }
+
+    (hi_coefficient, lo_coefficient, hi_exponent, sticky)
+}
+
+/// Adds two `(coefficient, exponent)` pairs, returning the coefficient and
+/// exponent of the sum, and whether nonzero digits were dropped on the way.
+/// If so, the sum is rounded toward negative infinity, as rounding expects.
+fn sum(a: (i128, i32), b: (i128, i32), limit: i128) -> (i128, i32, bool) {
+    let (hi, lo) = if a.1 > b.1 { (a, b) } else { (b, a) };
+    let (hi_coefficient, lo_coefficient, exponent, sticky) = align(hi, lo, limit);
+
+    // The digits dropped from `lo` were truncated toward zero.
+    if sticky && lo.0 < 0 {
+        (hi_coefficient + lo_coefficient - 1, exponent, sticky)
+    } else {
+        (hi_coefficient + lo_coefficient, exponent, sticky)
     }
 }
 
+/// Divides `dividend * 10^exponent` by a nonzero `divisor`, returning the
+/// coefficient and exponent of the quotient, and whether it is inexact.
+/// If so, the quotient is rounded toward negative infinity, as rounding expects.
+fn quotient(dividend: i128, divisor: i128, exponent: i32) -> (i128, i32, bool) {
+    let natural_exponent = exponent;
+    let mut exponent = exponent;
+    let (mut dividend, divisor) = if divisor < 0 { (-dividend, -divisor) } else { (dividend, divisor) };
+
+    // Scale the dividend up as far as it goes, so the quotient has more
+    // digits than a coefficient can hold.
+    while dividend != 0 && dividend.abs() <= i128::MAX / 10 {
+        dividend *= 10;
+        exponent -= 1;
+    }
+
+    let mut quotient = dividend.div_euclid(divisor);
+    let sticky = dividend.rem_euclid(divisor) != 0;
+
+    // An exact quotient gives back the zeros of the scaling.
+    if !sticky {
+        while quotient != 0 && quotient % 10 == 0 && exponent < natural_exponent {
+            quotient /= 10;
+            exponent += 1;
+        }
+    }
+
+    (quotient, exponent, sticky)
+}
+
 fn exponent_to_power_f64(e: i8) -> f64 {
     static POS_POWERS: [f64; 23] = [
           1.0,    1e1,    1e2,    1e3,    1e4,    1e5,    1e6,    1e7,
@@ -389,31 +640,35 @@ impl From<Dec64> for f32 {
     }
 }
 
+/// NaN converts to NaN, as do infinities and numbers too large to be represented.
 impl From<f64> for Dec64 {
     fn from(float: f64) -> Dec64 {
+        operation!("Dec64::from", float);
+
+        if float.is_nan() {
+            return nan!(NanReason::NanOperand, "Dec64::from", float);
+        } else if float.is_infinite() {
+            return nan!(NanReason::Overflow, "Dec64::from", float);
+        } else if float == 0.0 {
+            return ZERO;
+        }
+
         if float < 0.0 {
             let (coefficient, exponent) = grisu2::convert(-float);
 
-            Dec64::from_parts(-(coefficient as i64), exponent as i8)
+            Dec64::pack(-(coefficient as i64), exponent as i32)
         } else {
             let (coefficient, exponent) = grisu2::convert(float);
 
-            Dec64::from_parts(coefficient as i64, exponent as i8)
+            Dec64::pack(coefficient as i64, exponent as i32)
         }
     }
 }
 
+/// NaN converts to NaN, as do infinities.
 impl From<f32> for Dec64 {
     fn from(float: f32) -> Dec64 {
-        if float < 0.0 {
-            let (coefficient, exponent) = grisu2::convert(-float as f64);
-
-            Dec64::from_parts(-(coefficient as i64), exponent as i8)
-        } else {
-            let (coefficient, exponent) = grisu2::convert(float as f64);
-
-            Dec64::from_parts(coefficient as i64, exponent as i8)
-        }
+        Dec64::from(float as f64)
     }
 }
 
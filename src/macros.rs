@@ -13,3 +13,34 @@ macro_rules! dec64_parts {
 		dec64_raw!(($coefficient << 8) | ($exponent as u8 as i64))
 	)
 }
+
+/// Produces the NaN of an operation that went wrong for the given `NanReason`,
+/// raising the flag that goes with the reason in the current thread's `Context`,
+/// or in the `Flags` given before a semicolon. With the `strict` feature, panics
+/// instead, naming the operation and its operands, or those given to the
+/// outermost `operation!` being done.
+macro_rules! nan {
+    ( in $flags:expr ; $reason:expr, $operation:expr $( , $operand:expr )* ) => ({
+        ::reason::raise($reason, $flags);
+        nan!(@produce $reason, $operation $( , $operand )*)
+    });
+    ( @produce $reason:expr, $operation:expr $( , $operand:expr )* ) => ({
+        if cfg!(feature = "strict") {
+            ::reason::produced($reason, $operation, &[ $( &$operand ),* ]);
+        }
+
+        ::reason::nan($reason)
+    });
+    ( $reason:expr, $operation:expr $( , $operand:expr )* ) => ({
+        ::context::raise_current(|flags| ::reason::raise($reason, flags));
+        nan!(@produce $reason, $operation $( , $operand )*)
+    });
+}
+
+/// Names the public operation being done and its operands until the end of
+/// the enclosing block, for the panics of `nan!` with the `strict` feature.
+macro_rules! operation {
+    ( $operation:expr $( , $operand:expr )* ) => (
+        let _operation = ::reason::Operation::enter($operation, &[ $( &$operand ),* ]);
+    );
+}
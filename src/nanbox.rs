@@ -0,0 +1,103 @@
+//! NaN-boxing: tagging non-numeric values inside the NaN space of DEC64.
+//!
+//! A NaN has the exponent `-128` and leaves its 56-bit coefficient free. This
+//! module gives the coefficients with the top three bits set, from
+//! `0x70_0000_0000_0000` up, a meaning of their own: a tag in the next four
+//! bits, and 48 bits of data below it. Every other DEC64, ordinary NaNs and
+//! those with an unknown tag included, is a number. This way one 64-bit word,
+//! such as a slot in an interpreter, holds either a `Dec64` number or a
+//! reference to some other value.
+//!
+//! NaN payloads in this range are taken by boxed values, so they should not be
+//! used with `Dec64::nan_with_payload` alongside this module.
+
+use super::Dec64;
+
+/// Coefficients of boxed values have these bits set, and the sign bit clear.
+const BOX: i64 = 0x70_0000_0000_0000;
+/// How far the bits of `BOX` are shifted up.
+const BOX_SHIFT: u32 = 52;
+/// The bits of a coefficient that hold the tag.
+const TAG_MASK: i64 = 0x0F_0000_0000_0000;
+/// The bits of a coefficient that hold the data.
+const DATA_MASK: i64 = 0x00_FFFF_FFFF_FFFF;
+/// How far the tag is shifted up.
+const TAG_SHIFT: u32 = 48;
+
+const TAG_POINTER: i64 = 0;
+const TAG_HANDLE: i64 = 1;
+const TAG_BOOL: i64 = 2;
+const TAG_NULL: i64 = 3;
+
+/// A value that one DEC64 word can hold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    /// A number, which may be NaN.
+    Number(Dec64),
+    /// A pointer to an object, of which only the low 48 bits are kept.
+    Pointer(*const ()),
+    /// A small integer handle, such as an index into a table of objects.
+    Handle(u32),
+    /// A boolean.
+    Bool(bool),
+    /// The null value.
+    Null,
+}
+
+fn boxed(tag: i64, data: i64) -> Dec64 {
+    Dec64::from_parts(BOX | tag << TAG_SHIFT | data, -128)
+}
+
+/// Encodes a value into a single DEC64 word.
+///
+/// Returns `None` for a pointer that does not fit 48 bits, and for a NaN
+/// number that would decode as a boxed value.
+pub fn encode(value: Value) -> Option<Dec64> {
+    match value {
+        Value::Number(number) => {
+            if is_boxed(number) {
+                return None;
+            }
+
+            Some(number)
+        },
+        Value::Pointer(pointer) => {
+            let address = pointer as usize as u64;
+            if address > DATA_MASK as u64 {
+                return None;
+            }
+
+            Some(boxed(TAG_POINTER, address as i64))
+        },
+        Value::Handle(handle) => Some(boxed(TAG_HANDLE, handle as i64)),
+        Value::Bool(boolean) => Some(boxed(TAG_BOOL, boolean as i64)),
+        Value::Null => Some(boxed(TAG_NULL, 0)),
+    }
+}
+
+/// Decodes a DEC64 word. Every word that is not a boxed value is a number.
+pub fn decode(word: Dec64) -> Value {
+    unbox(word).unwrap_or(Value::Number(word))
+}
+
+/// Returns `true` if the word holds a boxed value rather than a number, and
+/// `false` otherwise.
+pub fn is_boxed(word: Dec64) -> bool {
+    unbox(word).is_some()
+}
+
+fn unbox(word: Dec64) -> Option<Value> {
+    let coefficient = word.coefficient();
+    if !word.is_nan() || coefficient >> BOX_SHIFT != BOX >> BOX_SHIFT {
+        return None;
+    }
+
+    let data = coefficient & DATA_MASK;
+    match (coefficient & TAG_MASK) >> TAG_SHIFT {
+        TAG_POINTER => Some(Value::Pointer(data as usize as *const ())),
+        TAG_HANDLE if data <= u32::MAX as i64 => Some(Value::Handle(data as u32)),
+        TAG_BOOL if data <= 1 => Some(Value::Bool(data == 1)),
+        TAG_NULL if data == 0 => Some(Value::Null),
+        _ => None,
+    }
+}
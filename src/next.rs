@@ -0,0 +1,92 @@
+//! Stepping between adjacent representable numbers.
+//!
+//! A number has many representations, but only the one with the largest
+//! coefficient, and so the smallest exponent, tells how close its neighbours
+//! are. All of these functions start by finding it.
+
+use super::{Dec64, MAX_COEFFICIENT, MAX_EXP, MIN_COEFFICIENT, MIN_EXP, MIN_POSITIVE};
+use reason::{pass_nan, NanReason};
+
+/// Scales a nonzero coefficient up for as long as it stays within `[min, max]`.
+fn finest(mut coefficient: i128, mut exponent: i32, min: i128, max: i128) -> (i128, i32) {
+    while exponent > MIN_EXP && min <= coefficient * 10 && coefficient * 10 <= max {
+        coefficient *= 10;
+        exponent -= 1;
+    }
+
+    (coefficient, exponent)
+}
+
+/// The smallest number above `coefficient * 10^exponent` with a coefficient
+/// within `[min, max]`, if there is one.
+fn step_up(coefficient: i128, exponent: i32, min: i128, max: i128) -> Option<(i128, i32)> {
+    if coefficient == 0 {
+        return Some((1, MIN_EXP));
+    }
+
+    let (coefficient, exponent) = finest(coefficient, exponent, min, max);
+
+    // A negative number that could not be scaled up once more may still have
+    // a neighbour with a smaller exponent, with a coefficient close to `min`.
+    let finer = (coefficient * 10 + 1).max(min);
+    if exponent > MIN_EXP && finer <= max && finer < (coefficient + 1) * 10 {
+        Some((finer, exponent - 1))
+    } else if coefficient < max {
+        Some((coefficient + 1, exponent))
+    } else if exponent < MAX_EXP {
+        Some((coefficient / 10 + 1, exponent + 1))
+    } else {
+        None
+    }
+}
+
+impl Dec64 {
+    /// Returns the smallest number greater than `self`.
+    ///
+    /// Returns NaN if the number is `MAX`, or if it is NaN.
+    pub fn next_up(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let (min, max) = (MIN_COEFFICIENT as i128, MAX_COEFFICIENT as i128);
+        match step_up(self.coefficient() as i128, self.exponent() as i32, min, max) {
+            Some((coefficient, exponent)) => Dec64::from_parts(coefficient as i64, exponent as i8).normalize(),
+            None => nan!(NanReason::Overflow, "Dec64::next_up", self),
+        }
+    }
+
+    /// Returns the greatest number less than `self`.
+    ///
+    /// Returns NaN if the number is `MIN`, or if it is NaN.
+    pub fn next_down(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        // Step up from the negated number, within the negated coefficient range.
+        let (min, max) = (-(MAX_COEFFICIENT as i128), -(MIN_COEFFICIENT as i128));
+        match step_up(-(self.coefficient() as i128), self.exponent() as i32, min, max) {
+            Some((coefficient, exponent)) => Dec64::from_parts(-coefficient as i64, exponent as i8).normalize(),
+            None => nan!(NanReason::Overflow, "Dec64::next_down", self),
+        }
+    }
+
+    /// Returns the unit in the last place of the number: `10^exponent` for
+    /// the smallest exponent that can represent it. This is the spacing of
+    /// the numbers around it, so `ONE.ulp()` is `EPSILON`.
+    ///
+    /// Returns `MIN_POSITIVE` if the number is zero, and NaN if it is NaN.
+    pub fn ulp(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if self.coefficient() == 0 {
+            return MIN_POSITIVE;
+        }
+
+        let (min, max) = (MIN_COEFFICIENT as i128, MAX_COEFFICIENT as i128);
+        let (_, exponent) = finest(self.coefficient() as i128, self.exponent() as i32, min, max);
+
+        Dec64::from_parts(1, exponent as i8)
+    }
+}
@@ -2,21 +2,27 @@
 
 use std::ops::{
     Add,
+    Div,
+    Mul,
     Neg,
     Sub,
 };
 
 use super::{
+    sum,
     Dec64,
     COEFFICIENT_MASK,
-    NAN,
     ZERO,
 };
+use context::{round_current, with_current};
+use reason::pass_nan;
 
 impl Add for Dec64 {
     type Output = Dec64;
 
     fn add(self, other: Dec64) -> Dec64 {
+        operation!("Dec64::add", self, other);
+
         let _sum_overflown = if self.exponent() == 0 && other.exponent() == 0 {
             // If the two exponents are both zero (which is usually the case for integers)
             // we can take the fast path. Since the exponents are both zero, we can simply
@@ -30,8 +36,8 @@ impl Add for Dec64 {
         } else {
             // The slow path is taken if the two operands do not both have zero exponents.
             if self.is_nan() {
-                // If the first operand is NaN return NaN.
-                return NAN;
+                // If the first operand is NaN return it.
+                return pass_nan(self);
             } else if self.exponent() == other.exponent() {
                 // The exponents match so we may add now. Zero out the exponents so there
                 // will be no carry into the coefficients when the coefficients are added.
@@ -46,7 +52,7 @@ impl Add for Dec64 {
                 // The slower path is taken when neither operand is nan, and their
                 // exponents are different.
                 if other.is_nan() {
-                    return NAN;
+                    return pass_nan(other);
                 }
 
                 // Before addition can take place, the exponents
@@ -57,53 +63,25 @@ impl Add for Dec64 {
                     (other, self)
                 };
 
-                let mut lo_coefficient = lo.coefficient();
                 // If lower value has zero coefficient return the higher.
-                if lo_coefficient == 0 {
+                if lo.coefficient() == 0 {
                     return hi;
                 }
-                let mut lo_exponent    = lo.exponent();
-                let mut hi_coefficient = hi.coefficient();
-                let mut hi_exponent    = hi.exponent();
-
-                // First we will try to decrease the first exponent. When we decrease the exponent
-                // by 1, we must also multiply the coefficient by 10. We can do this as long as
-                // there is no overflow. We have 8 extra bits to work with, so we can do this
-                // at least twice, possibly more.
-                loop {
-                    // Before decrementing the exponent, multiply.
-                    let (hi_coefficient_mul_10, overflow) = hi_coefficient.overflowing_mul(10);
-                    if overflow {
-                        // We cannot decrease exponent any more.
-                        break;
-                    }
-
-                    hi_exponent -= 1;
-                    hi_coefficient = hi_coefficient_mul_10;
-
-                    // Are the exponents equal yet?
-                    if hi_exponent == lo_exponent {
-                        // We can sum & then pack.
-                        let sum = hi_coefficient + lo_coefficient;
-                        return Self::pack(sum, hi_exponent as i32);
-                    }
-                }
 
-                // If we're still not done yet and we cannot decrease the first exponent any more,
-                // so we must instead try to increase the second exponent, which will result in
-                // a loss of significance.
-                // That is the heartbreak of floating point.
-                loop {
-                    lo_coefficient /= 10;
-                    lo_exponent += 1;
-
-                    // Are the exponents equal yet?
-                    if hi_exponent == lo_exponent {
-                        // We can sum & then pack.
-                        let sum = hi_coefficient + lo_coefficient;
-                        return Self::pack(sum, lo_exponent as i32);
-                    }
-                }
+                // First we will try to decrease the first exponent, multiplying its coefficient
+                // by 10 each time, for as long as it fits an i64. We have 8 extra bits to work
+                // with, so we can do this at least twice, possibly more. If that is not enough,
+                // the second exponent is increased, which results in a loss of significance.
+                // Digits dropped from the lower one are remembered as sticky, so
+                // that any rounding mode rounds the sum correctly.
+                let (sum, exponent, sticky) = sum(
+                    (hi.coefficient() as i128, hi.exponent() as i32),
+                    (lo.coefficient() as i128, lo.exponent() as i32),
+                    i64::MAX as i128,
+                );
+
+                // We can pack now.
+                return round_current(sum, exponent, sticky);
             }
         };
 
@@ -118,14 +96,41 @@ impl Add for Dec64 {
     }
 }
 
-/// Currently this doesn't have specialized implementation.
-/// Just add negated number.
+/// Rounds in the current thread's `Context`, as `Context::sub` does. Negating
+/// `other` first would round it on its own, so the difference is taken whole.
 impl Sub for Dec64 {
     type Output = Dec64;
 
     #[inline]
     fn sub(self, other: Dec64) -> Dec64 {
-        self.add(-other)
+        operation!("Dec64::sub", self, other);
+
+        with_current(|context| context.sub(self, other))
+    }
+}
+
+/// Rounds in the current thread's `Context`, as `Context::mul` does.
+impl Mul for Dec64 {
+    type Output = Dec64;
+
+    #[inline]
+    fn mul(self, other: Dec64) -> Dec64 {
+        operation!("Dec64::mul", self, other);
+
+        with_current(|context| context.mul(self, other))
+    }
+}
+
+/// Rounds in the current thread's `Context`, as `Context::div` does, which also
+/// records a division by zero there.
+impl Div for Dec64 {
+    type Output = Dec64;
+
+    #[inline]
+    fn div(self, other: Dec64) -> Dec64 {
+        operation!("Dec64::div", self, other);
+
+        with_current(|context| context.div(self, other))
     }
 }
 
@@ -133,8 +138,10 @@ impl Neg for Dec64 {
     type Output = Dec64;
 
     fn neg(self) -> Dec64 {
+        operation!("Dec64::neg", self);
+
         if self.is_nan() {
-            return NAN;
+            return pass_nan(self);
         }
 
         // If the coefficient is zero, then zero the exponent too.
@@ -0,0 +1,147 @@
+//! Reasons why an operation produced a NaN.
+//!
+//! With the `nan-reason` feature enabled, every NaN that an operation produces
+//! by itself carries its reason in the payload, and a NaN operand without a
+//! payload is passed on as `NanReason::NanOperand`. This costs nothing when the
+//! feature is disabled, in which case all of these NaNs are `NAN`.
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+use super::{Dec64, Flags, MIN_COEFFICIENT, NAN};
+
+/// Payloads of NaNs with a reason start just above this one.
+const REASON_BASE: i64 = MIN_COEFFICIENT;
+
+/// Why an operation produced a NaN, as decoded by `Dec64::nan_reason`.
+///
+/// A reason is encoded as the payload `MIN_COEFFICIENT + 1` for `Overflow`
+/// through `MIN_COEFFICIENT + 4` for `NanOperand`, so these payloads are best
+/// left alone by `Dec64::nan_with_payload`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NanReason {
+    /// The result was too large to be represented.
+    Overflow,
+    /// The operation has no meaningful result, such as the square root of a
+    /// negative number.
+    Invalid,
+    /// A nonzero number was divided by zero, or zero raised to a negative power.
+    DivisionByZero,
+    /// An operand was a NaN without a payload.
+    NanOperand,
+}
+
+impl NanReason {
+    fn payload(self) -> i64 {
+        REASON_BASE + match self {
+            NanReason::Overflow => 1,
+            NanReason::Invalid => 2,
+            NanReason::DivisionByZero => 3,
+            NanReason::NanOperand => 4,
+        }
+    }
+}
+
+/// The NaN an operation produces for the given reason.
+#[inline]
+pub(crate) fn nan(reason: NanReason) -> Dec64 {
+    if cfg!(feature = "nan-reason") {
+        return Dec64::nan_with_payload(reason.payload());
+    }
+
+    NAN
+}
+
+/// Raises the flag that goes with the reason: `overflow` and `inexact` for
+/// `Overflow`, `invalid` for `Invalid` and `division_by_zero` for
+/// `DivisionByZero`. Passing on a NaN operand raises none.
+pub(crate) fn raise(reason: NanReason, flags: &mut Flags) {
+    match reason {
+        NanReason::Overflow => {
+            flags.overflow = true;
+            flags.inexact = true;
+        },
+        NanReason::Invalid => flags.invalid = true,
+        NanReason::DivisionByZero => flags.division_by_zero = true,
+        NanReason::NanOperand => {},
+    }
+}
+
+/// Passes on a NaN operand, keeping its payload. Without one, it becomes
+/// `NanReason::NanOperand` if the `nan-reason` feature is enabled. Numbers
+/// that are not NaN are returned as they are.
+#[inline]
+pub(crate) fn pass_nan(operand: Dec64) -> Dec64 {
+    if cfg!(feature = "nan-reason") && operand.value == NAN.value {
+        return nan(NanReason::NanOperand);
+    }
+
+    operand
+}
+
+thread_local! {
+    /// The public operation being done on this thread, with its operands, as
+    /// named by the panics of the `strict` feature.
+    static OPERATION: RefCell<Option<(&'static str, String)>> = const { RefCell::new(None) };
+}
+
+/// Names the public operation being done on this thread until it is dropped,
+/// so that a NaN produced by the operations it is made of panics with its name.
+/// Only the outermost operation is named, and only with the `strict` feature.
+pub(crate) struct Operation {
+    outermost: bool,
+}
+
+impl Operation {
+    #[inline]
+    pub(crate) fn enter(name: &'static str, operands: &[&dyn Debug]) -> Operation {
+        if !cfg!(feature = "strict") {
+            return Operation { outermost: false };
+        }
+
+        OPERATION.with(|operation| {
+            let mut operation = operation.borrow_mut();
+            if operation.is_some() {
+                return Operation { outermost: false };
+            }
+            *operation = Some((name, format!("{:?}", operands)));
+
+            Operation { outermost: true }
+        })
+    }
+}
+
+impl Drop for Operation {
+    #[inline]
+    fn drop(&mut self) {
+        if self.outermost {
+            OPERATION.with(|operation| *operation.borrow_mut() = None);
+        }
+    }
+}
+
+/// Panics for the `strict` feature, naming the public operation being done if
+/// there is one, and otherwise the operation that produced the NaN.
+pub(crate) fn produced(reason: NanReason, name: &'static str, operands: &[&dyn Debug]) -> ! {
+    let (name, operands) = OPERATION
+        .with(|operation| operation.borrow_mut().take())
+        .unwrap_or_else(|| (name, format!("{:?}", operands)));
+
+    panic!("{} produced NaN ({:?}) from {}", name, reason, operands);
+}
+
+impl Dec64 {
+    /// Returns why an operation produced this NaN, if its payload encodes a
+    /// reason. Only with the `nan-reason` feature do operations encode them.
+    ///
+    /// Returns `None` if the number is not NaN, or if its payload is not a reason.
+    pub fn nan_reason(self) -> Option<NanReason> {
+        match self.nan_payload()? - REASON_BASE {
+            1 => Some(NanReason::Overflow),
+            2 => Some(NanReason::Invalid),
+            3 => Some(NanReason::DivisionByZero),
+            4 => Some(NanReason::NanOperand),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Square root, cube root, nth root and `hypot`.
+
+use super::{first_nan, Dec64, POWERS_10, ZERO};
+use context::round_current;
+use reason::{pass_nan, NanReason};
+use wide::{self, Wide};
+
+impl Dec64 {
+    /// Returns the square root of a number, correctly rounded in the rounding
+    /// mode of the current thread's `Context`.
+    ///
+    /// Exact roots, such as the square root of `2.25`, come out exact.
+    /// Returns NaN if the number is negative or NaN.
+    pub fn sqrt(self) -> Dec64 {
+        let coefficient = self.coefficient();
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if coefficient < 0 {
+            return nan!(NanReason::Invalid, "Dec64::sqrt", self);
+        } else if coefficient == 0 {
+            return ZERO;
+        }
+
+        // Scale the coefficient up to 36 or 37 digits, such that the exponent
+        // stays even. The integer square root then has more digits than
+        // a coefficient can hold, and it can be rounded correctly.
+        let digits = POWERS_10.iter().take_while(|&&power| power <= coefficient as u64).count() as i32;
+        let mut scale = 36 - digits;
+        if (self.exponent() as i32 - scale) % 2 != 0 {
+            scale += 1;
+        }
+
+        // The root is rounded down, so a remainder is left over as sticky.
+        let square = coefficient as u128 * 10u128.pow(scale as u32);
+        let root = wide::isqrt(square);
+        let sticky = root * root != square;
+
+        wide::trim_zeros(round_current(root as i128, (self.exponent() as i32 - scale) / 2, sticky))
+    }
+
+    /// Returns the cube root of a number.
+    ///
+    /// Negative numbers have negative cube roots. Returns NaN if the number is NaN.
+    #[inline]
+    pub fn cbrt(self) -> Dec64 {
+        operation!("Dec64::cbrt", self);
+
+        self.nth_root(3)
+    }
+
+    /// Returns the `n`th root of a number.
+    ///
+    /// Negative `n` returns the reciprocal of the root. Returns NaN if `n` is zero,
+    /// if `n` is even and the number is negative, or if the number is NaN.
+    pub fn nth_root(self, n: i32) -> Dec64 {
+        operation!("Dec64::nth_root", self, n);
+
+        if self.is_nan() {
+            return pass_nan(self);
+        } else if n == 0 {
+            return nan!(NanReason::Invalid, "Dec64::nth_root", self, n);
+        } else if n == 2 {
+            return self.sqrt();
+        } else if self.is_zero() {
+            // The reciprocal root of zero would be a division by zero.
+            return if n > 0 {
+                ZERO
+            } else {
+                nan!(NanReason::DivisionByZero, "Dec64::nth_root", self, n)
+            };
+        }
+
+        let x = Wide::from_dec64(self);
+        if x.is_negative() && n % 2 == 0 {
+            return nan!(NanReason::Invalid, "Dec64::nth_root", self, n);
+        }
+
+        let root = (x.abs().ln() / Wide::from_i64(n as i64)).exp();
+        let root = if x.is_negative() { -root } else { root };
+
+        root.to_dec64()
+    }
+
+    /// Returns the length of the hypotenuse of a right-angle triangle
+    /// with legs of length `self` and `other`, `sqrt(self² + other²)`.
+    ///
+    /// Returns NaN if either number is NaN.
+    pub fn hypot(self, other: Dec64) -> Dec64 {
+        operation!("Dec64::hypot", self, other);
+
+        if self.is_nan() || other.is_nan() {
+            return first_nan(self, other);
+        }
+
+        let x = Wide::from_dec64(self);
+        let y = Wide::from_dec64(other);
+
+        (x * x + y * y).sqrt().to_dec64()
+    }
+}
@@ -0,0 +1,262 @@
+//! Rounding modes, and rescaling a number to a chosen exponent.
+
+use std::cmp::{self, Ordering};
+use std::error::Error;
+use std::fmt;
+
+use super::{Context, Dec64, Flags, MAX_COEFFICIENT, MAX_EXP, MIN_COEFFICIENT, MIN_EXP, ZERO};
+use context::raise_current;
+use reason::NanReason;
+
+/// How to round a result that falls between two representable numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round to the nearest, ties away from zero. This is the default.
+    #[default]
+    HalfAwayFromZero,
+    /// Round to the nearest, ties to the even neighbour. Also known as banker's rounding.
+    HalfEven,
+    /// Round to the nearest, ties toward zero.
+    HalfTowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round toward zero, truncating.
+    TowardZero,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward negative infinity.
+    Floor,
+}
+
+impl RoundingMode {
+    /// Rounds `quotient + fraction` to an integer, where `quotient` was rounded
+    /// toward negative infinity and `fraction` is in `[0, 1)`. `half` compares
+    /// the fraction to one half, and `inexact` tells whether it is nonzero.
+    fn round(self, quotient: i128, half: Ordering, inexact: bool) -> i128 {
+        if !inexact {
+            return quotient;
+        }
+
+        let negative = quotient < 0;
+        let up = match self {
+            RoundingMode::HalfAwayFromZero => half == Ordering::Greater || (half == Ordering::Equal && !negative),
+            RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && quotient % 2 != 0),
+            RoundingMode::HalfTowardZero => half == Ordering::Greater || (half == Ordering::Equal && negative),
+            RoundingMode::AwayFromZero => !negative,
+            RoundingMode::TowardZero => negative,
+            RoundingMode::Ceiling => true,
+            RoundingMode::Floor => false,
+        };
+
+        if up { quotient + 1 } else { quotient }
+    }
+
+    /// Divides `value` by a positive `divisor`, rounding the quotient in this mode.
+    pub(crate) fn divide(self, value: i128, divisor: i128) -> i128 {
+        let remainder = value.rem_euclid(divisor);
+
+        self.round(value.div_euclid(divisor), remainder.cmp(&(divisor - remainder)), remainder != 0)
+    }
+}
+
+/// Rounds `(coefficient + sticky) * 10^exponent` into a DEC64, where `sticky`
+/// stands for a fraction in `(0, 1)` if set, and for zero otherwise. Only a
+/// coefficient that is too long to fit may come with `sticky` set.
+///
+/// Numbers that are too huge become NaN, and numbers that are too tiny
+/// become zero. Either is recorded in `flags`, as is any rounding.
+pub(crate) fn round_pack(coefficient: i128, exponent: i32, sticky: bool, mode: RoundingMode, flags: &mut Flags) -> Dec64 {
+    match try_round_pack(coefficient, exponent, sticky, mode, flags) {
+        Some(result) => result,
+        None => nan!(in flags; NanReason::Overflow, "Dec64::pack", coefficient, exponent),
+    }
+}
+
+/// Rounds as `round_pack` does, but returns `None` for numbers that are too
+/// huge, without raising the overflow flag. Rounding is still recorded.
+pub(crate) fn try_round_pack(coefficient: i128, exponent: i32, sticky: bool, mode: RoundingMode, flags: &mut Flags) -> Option<Dec64> {
+    if coefficient == 0 && !sticky {
+        return Some(ZERO);
+    }
+
+    // Drop as few digits as possible, but enough to reach the smallest exponent.
+    let mut dropped = cmp::max(MIN_EXP - exponent, sticky as i32);
+    let (mut coefficient, inexact) = loop {
+        let (quotient, half, inexact) = if dropped > 38 {
+            // Every digit is dropped, leaving a fraction below one half, or above
+            // it for a negative coefficient.
+            if coefficient < 0 {
+                (-1, Ordering::Greater, true)
+            } else {
+                (0, Ordering::Less, coefficient != 0 || sticky)
+            }
+        } else {
+            let divisor = 10i128.pow(dropped as u32);
+            let remainder = coefficient.rem_euclid(divisor);
+            let half = remainder.cmp(&(divisor - remainder)).then(if sticky { Ordering::Greater } else { Ordering::Equal });
+
+            (coefficient.div_euclid(divisor), half, remainder != 0 || sticky)
+        };
+
+        // Rounding up may still leave the coefficient too long, in which case
+        // one more digit has to go.
+        let rounded = mode.round(quotient, half, inexact);
+        if (MIN_COEFFICIENT as i128..=MAX_COEFFICIENT as i128).contains(&rounded) {
+            break (rounded, inexact);
+        }
+        dropped += 1;
+    };
+
+    if inexact {
+        flags.inexact = true;
+        if exponent < MIN_EXP {
+            flags.underflow = true;
+        }
+    }
+
+    if coefficient == 0 {
+        return Some(ZERO);
+    }
+
+    // An exponent that is too big can be brought back by scaling up the coefficient.
+    let mut exponent = exponent + dropped;
+    while exponent > MAX_EXP && (MIN_COEFFICIENT as i128..=MAX_COEFFICIENT as i128).contains(&(coefficient * 10)) {
+        coefficient *= 10;
+        exponent -= 1;
+    }
+
+    if exponent > MAX_EXP {
+        return None;
+    }
+
+    let coefficient = coefficient as i64;
+
+    Some(dec64_parts!(coefficient, exponent))
+}
+
+/// The error returned by `Dec64::quantize` when the number can not be given
+/// the requested exponent, because its coefficient would be out of range or
+/// because it is NaN. Rounding is not an error: it raises the `inexact` flag
+/// of the current thread's `Context`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("number is out of range for the requested exponent")
+    }
+}
+
+impl Error for OutOfRange {}
+
+/// The error returned by conversions that succeed only if the number can be
+/// represented exactly, without rounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Inexact;
+
+impl fmt::Display for Inexact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("number can not be represented exactly with the requested exponent")
+    }
+}
+
+impl Error for Inexact {}
+
+impl Dec64 {
+    /// Combines the coefficient and exponent into a DEC64 like `Dec64::pack`,
+    /// but rounding in the given mode instead of that of the current `Context`,
+    /// whose flags are left alone.
+    pub fn pack_with(coefficient: i64, exponent: i32, mode: RoundingMode) -> Dec64 {
+        operation!("Dec64::pack_with", coefficient, exponent, mode);
+
+        round_pack(coefficient as i128, exponent, false, mode, &mut Flags::default())
+    }
+
+    /// Adds two numbers, rounding in the given mode.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if either number is NaN.
+    pub fn add_with(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        operation!("Dec64::add_with", self, other, mode);
+
+        Context::new(mode).add(self, other)
+    }
+
+    /// Subtracts `other` from the number, rounding in the given mode.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if either number is NaN.
+    pub fn sub_with(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        operation!("Dec64::sub_with", self, other, mode);
+
+        Context::new(mode).sub(self, other)
+    }
+
+    /// Multiplies two numbers, rounding in the given mode.
+    ///
+    /// Returns NaN if the result is too large to be represented, or if either number is NaN.
+    pub fn mul_with(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        operation!("Dec64::mul_with", self, other, mode);
+
+        Context::new(mode).mul(self, other)
+    }
+
+    /// Divides the number by `other`, rounding in the given mode.
+    ///
+    /// Returns NaN if `other` is zero, if the result is too large to be
+    /// represented, or if either number is NaN.
+    pub fn div_with(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        operation!("Dec64::div_with", self, other, mode);
+
+        Context::new(mode).div(self, other)
+    }
+
+    /// Rescales the number to the given exponent, rounding the coefficient in
+    /// the given mode if digits have to be dropped. A zero keeps its exponent,
+    /// so quantizing `ZERO` to `-2` gives `0e-2`.
+    ///
+    /// Dropping nonzero digits raises the `inexact` flag of the current thread's
+    /// `Context`, while the rounded number is still returned.
+    ///
+    /// Returns `Err(OutOfRange)` if the rescaled coefficient does not fit between
+    /// `MIN_COEFFICIENT` and `MAX_COEFFICIENT`, if the exponent is `-128`, or if
+    /// the number is NaN.
+    pub fn quantize(self, exponent: i8, mode: RoundingMode) -> Result<Dec64, OutOfRange> {
+        let (result, inexact) = self.rescale(exponent, mode)?;
+        if inexact {
+            raise_current(|flags| flags.inexact = true);
+        }
+
+        Ok(result)
+    }
+
+    /// Does the work of `quantize`, returning whether nonzero digits were
+    /// dropped instead of raising a flag.
+    pub(crate) fn rescale(self, exponent: i8, mode: RoundingMode) -> Result<(Dec64, bool), OutOfRange> {
+        if self.is_nan() || exponent == -128 {
+            return Err(OutOfRange);
+        }
+
+        let coefficient = self.coefficient() as i128;
+        let shift = exponent as i32 - self.exponent() as i32;
+        let (coefficient, inexact) = if shift >= 0 {
+            // A coefficient has fewer than 18 digits, so a larger divisor
+            // rounds it the same way as this one.
+            let divisor = 10i128.pow(shift.min(18) as u32);
+
+            (mode.divide(coefficient, divisor), coefficient % divisor != 0)
+        } else if coefficient == 0 {
+            (0, false)
+        } else if shift < -18 {
+            return Err(OutOfRange);
+        } else {
+            (coefficient * 10i128.pow(-shift as u32), false)
+        };
+
+        if coefficient < MIN_COEFFICIENT as i128 || coefficient > MAX_COEFFICIENT as i128 {
+            return Err(OutOfRange);
+        }
+
+        let coefficient = coefficient as i64;
+
+        Ok((dec64_parts!(coefficient, exponent), inexact))
+    }
+}
@@ -0,0 +1,75 @@
+//! Conversions to and from scaled integers, such as amounts in minor units.
+//!
+//! A scale counts digits after the decimal point, so it is the negated
+//! exponent: `12.34` is `1234` with scale `2`.
+
+use super::Dec64;
+
+/// Scales beyond this make any coefficient overflow to NaN or underflow to zero.
+const MAX_SCALE: i32 = 1000;
+
+impl Dec64 {
+    /// Returns the number as an integer count of `10^-scale`, so `12.34` with
+    /// scale `2` gives `1234`.
+    ///
+    /// Returns `None` if that would drop nonzero digits, if it does not fit an
+    /// `i64`, or if the number is NaN.
+    pub fn to_scaled_i64(self, scale: i32) -> Option<i64> {
+        if self.is_nan() {
+            return None;
+        }
+
+        let coefficient = self.coefficient();
+        if coefficient == 0 {
+            return Some(0);
+        }
+
+        // Clamped like `from_unscaled`, the shift can not overflow, and any
+        // scale beyond the clamp gives `None` all the same.
+        let shift = self.exponent() as i32 + scale.clamp(-MAX_SCALE, MAX_SCALE);
+        if shift >= 0 {
+            coefficient.checked_mul(10i64.checked_pow(shift as u32)?)
+        } else {
+            let divisor = 10i64.checked_pow(-shift as u32)?;
+            if coefficient % divisor != 0 {
+                return None;
+            }
+
+            Some(coefficient / divisor)
+        }
+    }
+
+    /// Constructs a number from an integer count of `10^-scale`, so `1234`
+    /// with scale `2` gives `12.34`.
+    ///
+    /// The value is rounded if it does not fit the coefficient. Returns NaN if
+    /// the result is too large to be represented.
+    pub fn from_scaled_i64(value: i64, scale: i32) -> Dec64 {
+        operation!("Dec64::from_scaled_i64", value, scale);
+
+        Self::from_unscaled(value as i128, scale)
+    }
+
+    /// Returns the unscaled value and the scale of the number, as used by
+    /// Java's `BigDecimal`. This is the coefficient and the negated exponent.
+    ///
+    /// Returns `None` if the number is NaN.
+    pub fn to_unscaled(self) -> Option<(i128, i32)> {
+        if self.is_nan() {
+            return None;
+        }
+
+        Some((self.coefficient() as i128, -(self.exponent() as i32)))
+    }
+
+    /// Constructs a number from an unscaled value and a scale, as used by
+    /// Java's `BigDecimal`.
+    ///
+    /// The value is rounded if it does not fit the coefficient. Returns NaN if
+    /// the result is too large to be represented.
+    pub fn from_unscaled(unscaled: i128, scale: i32) -> Dec64 {
+        operation!("Dec64::from_unscaled", unscaled, scale);
+
+        Self::pack_i128(unscaled, -scale.clamp(-MAX_SCALE, MAX_SCALE))
+    }
+}
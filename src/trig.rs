@@ -0,0 +1,285 @@
+//! Trigonometric functions and their inverses.
+//!
+//! Arguments are reduced by multiples of π/2 held to 72 digits, which is
+//! exact for any argument below `10^18` in magnitude. Larger arguments can
+//! not be reduced meaningfully and produce NaN.
+//!
+//! An argument that is the correctly rounded DEC64 value of a multiple of
+//! π/12, such as `consts::PI` or `consts::FRAC_PI_6`, is taken to be that
+//! exact multiple. This way `sin(consts::PI)` is zero, `cos(consts::FRAC_PI_3)`
+//! is one half, and `tan(consts::FRAC_PI_4)` is one.
+
+use super::{first_nan, Dec64, RoundingMode};
+use reason::{pass_nan, NanReason};
+use wide::{self, Wide, ONE};
+
+/// Reduce the argument to `x = k * π/2 + r`, with `r` in `[-π/4, π/4]`,
+/// returning `k` modulo 4, `r`, and the multiple of π/12 that `r` was snapped
+/// to, if any.
+fn reduce(x: Dec64) -> Option<(i64, Wide, Option<i64>)> {
+    let x_wide = Wide::from_dec64(x);
+    if x_wide.abs() >= Wide::from_i64(1_000_000_000_000_000_000) {
+        return None;
+    }
+
+    let k = (x_wide * wide::FRAC_2_PI).round_to_i64();
+    let k_wide = Wide::from_i64(k);
+    let r = wide::FRAC_PI_2_PARTS.iter().fold(x_wide, |r, &part| r - k_wide * part);
+
+    // Snap to the multiple of π/12 that the argument is a rounding of, if any.
+    // Only a rounding to 16 or more digits, with at least one of them in the
+    // fraction, identifies a multiple both uniquely and deliberately. The
+    // constants are rounded to the nearest, whatever the current `Context`.
+    let twelfths = (r / wide::FRAC_PI_12).round_to_i64();
+    let multiple = (Wide::from_i64(6 * k + twelfths) * wide::FRAC_PI_12).to_dec64_with(RoundingMode::HalfAwayFromZero);
+    if multiple == x && multiple.exponent() < 0 && multiple.coefficient().abs() >= 1_000_000_000_000_000 {
+        return Some((k.rem_euclid(4), Wide::from_i64(twelfths) * wide::FRAC_PI_12, Some(twelfths)));
+    }
+
+    Some((k.rem_euclid(4), r, None))
+}
+
+/// Taylor series of sine, for small arguments.
+fn sin_series(r: Wide) -> Wide {
+    let r2 = r * r;
+    let mut sum = r;
+    let mut term = r;
+    let mut n = 1;
+    loop {
+        term = -(term * r2) / Wide::from_i64((n + 1) * (n + 2));
+        n += 2;
+        if term.is_negligible(sum) {
+            return sum;
+        }
+        sum = sum + term;
+    }
+}
+
+/// Taylor series of cosine, for small arguments.
+fn cos_series(r: Wide) -> Wide {
+    let r2 = r * r;
+    let mut sum = ONE;
+    let mut term = ONE;
+    let mut n = 0;
+    loop {
+        term = -(term * r2) / Wide::from_i64((n + 1) * (n + 2));
+        n += 2;
+        if term.is_negligible(sum) {
+            return sum;
+        }
+        sum = sum + term;
+    }
+}
+
+/// Sine and cosine of a reduced argument in quadrant `k`. Where `r` is a
+/// multiple of π/12 with a rational sine, the sine is exact, and the cosine
+/// of ±π/4 is the sine's magnitude, so that no rounding mode moves them.
+fn sin_cos_reduced(k: i64, r: Wide, twelfths: Option<i64>) -> (Wide, Wide) {
+    let (sin, cos) = match twelfths {
+        Some(2) => (ONE.half(), cos_series(r)),
+        Some(-2) => (-ONE.half(), cos_series(r)),
+        Some(3) | Some(-3) => {
+            let sin = sin_series(r);
+
+            (sin, sin.abs())
+        },
+        _ => (sin_series(r), cos_series(r)),
+    };
+
+    match k {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Arctangent, for any argument.
+fn atan_wide(x: Wide) -> Wide {
+    if x.abs() > ONE {
+        // atan(x) = ±π/2 - atan(1/x)
+        let quarter_turn = if x.is_negative() { -wide::FRAC_PI_2 } else { wide::FRAC_PI_2 };
+
+        return quarter_turn - atan_wide(ONE / x);
+    }
+
+    // Halve the angle three times with atan(x) = 2 * atan(x / (1 + sqrt(1 + x²))),
+    // which leaves an argument below tan(π/32) for the series to converge quickly.
+    let mut y = x;
+    for _ in 0..3 {
+        y = y / (ONE + (ONE + y * y).sqrt());
+    }
+
+    let y2 = y * y;
+    let mut sum = y;
+    let mut power = y;
+    let mut n = 1;
+    loop {
+        n += 2;
+        power = -(power * y2);
+        let term = power / Wide::from_i64(n);
+        if term.is_negligible(sum) {
+            break;
+        }
+        sum = sum + term;
+    }
+
+    sum * Wide::from_i64(8)
+}
+
+impl Dec64 {
+    /// Returns the sine of a number in radians.
+    ///
+    /// Returns NaN if the number is NaN, or if its magnitude is `10^18` or more.
+    pub fn sin(self) -> Dec64 {
+        operation!("Dec64::sin", self);
+
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine of a number in radians.
+    ///
+    /// Returns NaN if the number is NaN, or if its magnitude is `10^18` or more.
+    pub fn cos(self) -> Dec64 {
+        operation!("Dec64::cos", self);
+
+        self.sin_cos().1
+    }
+
+    /// Returns the tangent of a number in radians.
+    ///
+    /// Returns NaN at odd multiples of π/2, if the number is NaN, or if its
+    /// magnitude is `10^18` or more.
+    pub fn tan(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        match reduce(self) {
+            Some((k, r, twelfths)) => {
+                let (sin, cos) = sin_cos_reduced(k, r, twelfths);
+                if cos.is_zero() {
+                    return nan!(NanReason::DivisionByZero, "Dec64::tan", self);
+                }
+
+                (sin / cos).to_dec64()
+            },
+            None => nan!(NanReason::Invalid, "Dec64::tan", self),
+        }
+    }
+
+    /// Simultaneously computes the sine and cosine of a number in radians.
+    /// Returns `(sin(x), cos(x))`.
+    ///
+    /// Returns NaNs if the number is NaN, or if its magnitude is `10^18` or more.
+    pub fn sin_cos(self) -> (Dec64, Dec64) {
+        if self.is_nan() {
+            return (pass_nan(self), pass_nan(self));
+        }
+
+        match reduce(self) {
+            Some((k, r, twelfths)) => {
+                let (sin, cos) = sin_cos_reduced(k, r, twelfths);
+
+                (sin.to_dec64(), cos.to_dec64())
+            },
+            None => {
+                let nan = nan!(NanReason::Invalid, "Dec64::sin_cos", self);
+
+                (nan, nan)
+            },
+        }
+    }
+
+    /// Returns the arcsine of a number, in radians in the range `[-π/2, π/2]`.
+    ///
+    /// Returns NaN if the number is outside of the range `[-1, 1]`, or if it is NaN.
+    pub fn asin(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let x = Wide::from_dec64(self);
+        if x.abs() > ONE {
+            return nan!(NanReason::Invalid, "Dec64::asin", self);
+        }
+
+        // asin(x) = atan(x / sqrt(1 - x²))
+        let cos = ((ONE - x) * (ONE + x)).sqrt();
+        let asin = if cos.is_zero() {
+            if x.is_negative() { -wide::FRAC_PI_2 } else { wide::FRAC_PI_2 }
+        } else {
+            atan_wide(x / cos)
+        };
+
+        asin.to_dec64()
+    }
+
+    /// Returns the arccosine of a number, in radians in the range `[0, π]`.
+    ///
+    /// Returns NaN if the number is outside of the range `[-1, 1]`, or if it is NaN.
+    pub fn acos(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        let x = Wide::from_dec64(self);
+        if x.abs() > ONE {
+            return nan!(NanReason::Invalid, "Dec64::acos", self);
+        } else if x == -ONE {
+            return wide::PI.to_dec64();
+        }
+
+        // acos(x) = 2 * atan(sqrt((1 - x) / (1 + x)))
+        let half = atan_wide(((ONE - x) / (ONE + x)).sqrt());
+
+        (half + half).to_dec64()
+    }
+
+    /// Returns the arctangent of a number, in radians in the range `[-π/2, π/2]`.
+    ///
+    /// Returns NaN if the number is NaN.
+    pub fn atan(self) -> Dec64 {
+        if self.is_nan() {
+            return pass_nan(self);
+        }
+
+        atan_wide(Wide::from_dec64(self)).to_dec64()
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`),
+    /// in radians in the range `[-π, π]`.
+    ///
+    /// * `x = 0`, `y = 0`: `0`
+    /// * `x = 0`: `±π/2`
+    /// * `x > 0`: `atan(y/x)` -> `(-π/2, π/2)`
+    /// * `x < 0`, `y >= 0`: `atan(y/x) + π` -> `(π/2, π]`
+    /// * `x < 0`, `y < 0`: `atan(y/x) - π` -> `(-π, -π/2)`
+    ///
+    /// Returns NaN if either number is NaN.
+    pub fn atan2(self, other: Dec64) -> Dec64 {
+        if self.is_nan() || other.is_nan() {
+            return first_nan(self, other);
+        }
+
+        let y = Wide::from_dec64(self);
+        let x = Wide::from_dec64(other);
+        let atan2 = if x.is_zero() {
+            if y.is_zero() {
+                y
+            } else if y.is_negative() {
+                -wide::FRAC_PI_2
+            } else {
+                wide::FRAC_PI_2
+            }
+        } else if !x.is_negative() {
+            atan_wide(y / x)
+        } else if !y.is_negative() {
+            atan_wide(y / x) + wide::PI
+        } else {
+            atan_wide(y / x) - wide::PI
+        };
+
+        atan2.to_dec64()
+    }
+}
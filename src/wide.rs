@@ -0,0 +1,524 @@
+//! Extended precision decimal arithmetic backing the math functions.
+//!
+//! A `Wide` holds a sign, an unsigned 128-bit coefficient normalized to
+//! exactly 36 decimal digits, and an exponent. Intermediate results are
+//! kept in this form and only rounded into a `Dec64` once, at the very end.
+//!
+//! Operations truncate, but whenever nonzero digits are discarded the last
+//! digit of the coefficient is made odd, so that the result still reads as
+//! inexact to the final rounding.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{Dec64, Flags, RoundingMode, ZERO};
+use context::{round_current, try_round_current};
+use round::round_pack;
+
+/// `10^18`, used to split coefficients into halves when multiplying.
+const SPLIT: u128 = 1_000_000_000_000_000_000;
+/// `10^17`.
+const SPLIT_TENTH: u128 = 100_000_000_000_000_000;
+/// Smallest normalized coefficient, `10^35`.
+const MIN_NORMAL: u128 = 100_000_000_000_000_000_000_000_000_000_000_000;
+/// Smallest coefficient that is too big to be normalized, `10^36`.
+const MAX_NORMAL: u128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
+
+/// Extended precision decimal value.
+#[derive(Clone, Copy, Debug)]
+pub struct Wide {
+    negative: bool,
+    coefficient: u128,
+    exponent: i32,
+}
+
+/// Shorthand for a positive `Wide` constant with a normalized coefficient.
+macro_rules! wide {
+    ( $coefficient:expr, $exponent:expr ) => (
+        Wide { negative: false, coefficient: $coefficient, exponent: $exponent }
+    )
+}
+
+/// `0`.
+pub const WIDE_ZERO: Wide = Wide { negative: false, coefficient: 0, exponent: 0 };
+/// `1`.
+pub const ONE: Wide = wide!(MIN_NORMAL, -35);
+/// `0.75`.
+const THREE_QUARTERS: Wide = wide!(750_000_000_000_000_000_000_000_000_000_000_000, -36);
+/// `1.5`.
+const THREE_HALVES: Wide = wide!(150_000_000_000_000_000_000_000_000_000_000_000, -35);
+/// `1/256`.
+const FRAC_1_256: Wide = wide!(390_625_000_000_000_000_000_000_000_000_000_000, -38);
+
+/// ln(10), the leading digits of which are `consts::LN_10`.
+pub const LN_10: Wide = wide!(230_258_509_299_404_568_401_799_145_468_436_421, -35);
+/// ln(2), the leading digits of which are `consts::LN_2`.
+pub const LN_2: Wide = wide!(693_147_180_559_945_309_417_232_121_458_176_568, -36);
+
+/// 2/π, the leading digits of which are `consts::FRAC_2_PI`.
+pub const FRAC_2_PI: Wide = wide!(636_619_772_367_581_343_075_535_053_490_057_448, -36);
+/// π, the leading digits of which are `consts::PI`.
+pub const PI: Wide = wide!(314_159_265_358_979_323_846_264_338_327_950_288, -35);
+/// π/2, the leading digits of which are `consts::FRAC_PI_2`.
+pub const FRAC_PI_2: Wide = wide!(157_079_632_679_489_661_923_132_169_163_975_144, -35);
+/// π/12, the smallest of the `consts` π fractions.
+pub const FRAC_PI_12: Wide = wide!(261_799_387_799_149_436_538_553_615_273_291_907, -36);
+/// π/2 split into parts of 18 digits each. The product of any of these with
+/// an integer of up to 18 digits is exact, which makes them suitable for
+/// argument reduction.
+pub const FRAC_PI_2_PARTS: [Wide; 4] = [
+    wide!(157_079_632_679_489_661_000_000_000_000_000_000, -35),
+    wide!(923_132_169_163_975_144_000_000_000_000_000_000, -53),
+    wide!(209_858_469_968_755_291_000_000_000_000_000_000, -71),
+    wide!(487_472_296_153_908_200_000_000_000_000_000_000, -90),
+];
+
+impl Wide {
+    /// Construct a normalized `Wide` from sign, coefficient and exponent.
+    pub fn new(negative: bool, mut coefficient: u128, mut exponent: i32) -> Wide {
+        if coefficient == 0 {
+            return WIDE_ZERO;
+        }
+
+        let mut inexact = false;
+        while coefficient >= MAX_NORMAL {
+            inexact |= !coefficient.is_multiple_of(10);
+            coefficient /= 10;
+            exponent += 1;
+        }
+        if inexact {
+            coefficient |= 1;
+        }
+        while coefficient < MIN_NORMAL {
+            coefficient *= 10;
+            exponent -= 1;
+        }
+
+        Wide { negative, coefficient, exponent }
+    }
+
+    /// Convert a DEC64 value. The value must not be NaN.
+    pub fn from_dec64(dec: Dec64) -> Wide {
+        let coefficient = dec.coefficient();
+
+        Wide::new(coefficient < 0, coefficient.unsigned_abs() as u128, dec.exponent() as i32)
+    }
+
+    pub fn from_i64(num: i64) -> Wide {
+        Wide::new(num < 0, num.unsigned_abs() as u128, 0)
+    }
+
+    /// Round into a DEC64 value, in the rounding mode of the current thread's
+    /// `Context`. Exponents that are out of range are handled as by `Dec64::pack`.
+    ///
+    /// Trailing zeros left over after rounding are removed from the fraction.
+    pub fn to_dec64(self) -> Dec64 {
+        if self.coefficient == 0 {
+            return ZERO;
+        }
+
+        // All 36 digits fit an i128, and an odd last digit already marks any
+        // digits discarded before, so no sticky flag is needed.
+        let coefficient = self.coefficient as i128;
+        let coefficient = if self.negative { -coefficient } else { coefficient };
+
+        trim_zeros(round_current(coefficient, self.exponent, false))
+    }
+
+    /// Round into a DEC64 value as `to_dec64` does, but return `None` if the
+    /// value is too large, without recording that in the current `Context`.
+    pub fn try_to_dec64(self) -> Option<Dec64> {
+        if self.coefficient == 0 {
+            return Some(ZERO);
+        }
+
+        let coefficient = self.coefficient as i128;
+        let coefficient = if self.negative { -coefficient } else { coefficient };
+
+        try_round_current(coefficient, self.exponent, false).map(trim_zeros)
+    }
+
+    /// Round into a DEC64 value as `to_dec64` does, but in the given rounding
+    /// mode, leaving the current `Context` alone.
+    pub fn to_dec64_with(self, mode: RoundingMode) -> Dec64 {
+        if self.coefficient == 0 {
+            return ZERO;
+        }
+
+        let coefficient = self.coefficient as i128;
+        let coefficient = if self.negative { -coefficient } else { coefficient };
+
+        trim_zeros(round_pack(coefficient, self.exponent, false, mode, &mut Flags::default()))
+    }
+
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.coefficient == 0
+    }
+
+    #[inline]
+    pub fn is_negative(self) -> bool {
+        self.negative
+    }
+
+    #[inline]
+    pub fn abs(self) -> Wide {
+        Wide { negative: false, ..self }
+    }
+
+    /// Multiply by `10^power`.
+    #[inline]
+    pub fn scale(self, power: i32) -> Wide {
+        if self.is_zero() {
+            return self;
+        }
+
+        Wide { exponent: self.exponent + power, ..self }
+    }
+
+    /// Divide by two.
+    #[inline]
+    pub fn half(self) -> Wide {
+        Wide::new(self.negative, self.coefficient * 5, self.exponent - 1)
+    }
+
+    /// Returns `true` if adding this term to `sum` could no longer change
+    /// any of its digits.
+    #[inline]
+    pub fn is_negligible(self, sum: Wide) -> bool {
+        self.is_zero() || self.exponent + 38 < sum.exponent
+    }
+
+    /// Round to the nearest integer, with halves rounded away from zero.
+    ///
+    /// The value must be less than `10^18` in magnitude.
+    pub fn round_to_i64(self) -> i64 {
+        let magnitude = if self.exponent >= 0 {
+            self.coefficient * 10u128.pow(self.exponent as u32)
+        } else if self.exponent >= -36 {
+            let power = 10u128.pow(-self.exponent as u32);
+
+            (self.coefficient + power / 2) / power
+        } else {
+            0
+        };
+
+        if self.negative { -(magnitude as i64) } else { magnitude as i64 }
+    }
+
+    /// Square root. The value must not be negative.
+    pub fn sqrt(self) -> Wide {
+        if self.is_zero() {
+            return self;
+        }
+
+        // Make the exponent even, estimate the root to 18 digits with an
+        // integer square root, and polish it with a single Newton step.
+        let (coefficient, exponent) = if self.exponent % 2 != 0 {
+            (self.coefficient * 10, self.exponent - 1)
+        } else {
+            (self.coefficient, self.exponent)
+        };
+        let estimate = Wide::new(false, isqrt(coefficient), exponent / 2);
+
+        (estimate + self / estimate).half()
+    }
+
+    /// Natural exponential function.
+    pub fn exp(self) -> Wide {
+        // Anything beyond this range under- or overflows DEC64 regardless.
+        let limit = Wide::from_i64(1000);
+        let x = self.clamp(-limit, limit);
+
+        // Split off a power of ten, and scale the rest down so that the
+        // series converges quickly.
+        let power = (x / LN_10).round_to_i64();
+        let r = (x - Wide::from_i64(power) * LN_10) * FRAC_1_256;
+
+        let mut sum = ONE + r;
+        let mut term = r;
+        let mut n = 1;
+        loop {
+            n += 1;
+            term = term * r / Wide::from_i64(n);
+            if term.is_negligible(sum) {
+                break;
+            }
+            sum = sum + term;
+        }
+
+        // Undo the scaling: e^r = (e^(r/256))^256.
+        for _ in 0..8 {
+            sum = sum * sum;
+        }
+
+        sum.scale(power as i32)
+    }
+
+    /// Natural logarithm. The value must be positive.
+    pub fn ln(self) -> Wide {
+        // Close to one the series converges directly, and splitting off
+        // powers of ten would only cancel out.
+        let (power, mut m) = if THREE_QUARTERS <= self && self < THREE_HALVES {
+            (0, self)
+        } else {
+            (self.exponent + 35, Wide { exponent: -35, ..self })
+        };
+
+        let mut halvings = 0;
+        while m >= THREE_HALVES {
+            m = m.half();
+            halvings += 1;
+        }
+
+        // ln(m) = 2 * atanh((m - 1) / (m + 1))
+        let ln_m = ((m - ONE) / (m + ONE)).atanh_series();
+
+        Wide::from_i64(power as i64) * LN_10 + Wide::from_i64(halvings) * LN_2 + ln_m + ln_m
+    }
+
+    /// Natural logarithm of one plus the value, which stays accurate when the
+    /// value is close to zero. The value must be greater than minus one.
+    pub fn ln_1p(self) -> Wide {
+        if self.abs() >= ONE.half() {
+            return (ONE + self).ln();
+        }
+
+        // ln(1 + u) = 2 * atanh(u / (2 + u))
+        let ln = (self / (Wide::from_i64(2) + self)).atanh_series();
+
+        ln + ln
+    }
+
+    /// Taylor series of the inverse hyperbolic tangent, for small values.
+    fn atanh_series(self) -> Wide {
+        let z2 = self * self;
+        let mut sum = self;
+        let mut power = self;
+        let mut n = 1;
+        loop {
+            n += 2;
+            power = power * z2;
+            let term = power / Wide::from_i64(n);
+            if term.is_negligible(sum) {
+                return sum;
+            }
+            sum = sum + term;
+        }
+    }
+
+    /// Raise to an integer power by repeated squaring.
+    pub fn powi(self, n: i64) -> Wide {
+        let mut base = self.abs();
+        let mut result = ONE;
+        let mut bits = n.unsigned_abs();
+        while bits > 0 {
+            if bits & 1 == 1 {
+                result = result * base;
+            }
+            bits >>= 1;
+
+            // Once the magnitude is far outside of what DEC64 can hold, the
+            // remaining squarings could only push it further out.
+            if base.exponent.abs() > 1000 {
+                if bits > 0 {
+                    result = result * base;
+                }
+                break;
+            }
+            base = base * base;
+        }
+
+        if n < 0 {
+            result = ONE / result;
+        }
+
+        if self.negative && n & 1 == 1 { -result } else { result }
+    }
+}
+
+impl PartialEq for Wide {
+    fn eq(&self, other: &Wide) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Wide {}
+
+impl PartialOrd for Wide {
+    fn partial_cmp(&self, other: &Wide) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Wide {
+    fn cmp(&self, other: &Wide) -> Ordering {
+        let sign = |w: &Wide| if w.is_zero() { 0 } else if w.negative { -1 } else { 1 };
+        let (sign_self, sign_other) = (sign(self), sign(other));
+        if sign_self != sign_other || sign_self == 0 {
+            return sign_self.cmp(&sign_other);
+        }
+
+        // Normalized coefficients all have the same number of digits, so
+        // magnitudes order by exponent first.
+        let magnitude = (self.exponent, self.coefficient).cmp(&(other.exponent, other.coefficient));
+        if self.negative { magnitude.reverse() } else { magnitude }
+    }
+}
+
+impl Neg for Wide {
+    type Output = Wide;
+
+    #[inline]
+    fn neg(self) -> Wide {
+        if self.is_zero() {
+            return self;
+        }
+
+        Wide { negative: !self.negative, ..self }
+    }
+}
+
+impl Add for Wide {
+    type Output = Wide;
+
+    fn add(self, other: Wide) -> Wide {
+        if other.is_zero() {
+            return self;
+        } else if self.is_zero() {
+            return other;
+        }
+
+        let (hi, lo) = if self.exponent >= other.exponent {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        // Give the larger operand two guard digits, then align the smaller
+        // operand to it. Whatever falls off the end is kept as a sticky digit.
+        let hi_coefficient = hi.coefficient * 100;
+        let shift = hi.exponent - 2 - lo.exponent;
+        let lo_coefficient = if shift < 0 {
+            lo.coefficient * 10u128.pow(-shift as u32)
+        } else if shift <= 38 {
+            let power = 10u128.pow(shift as u32);
+            let aligned = lo.coefficient / power;
+
+            if lo.coefficient % power != 0 { aligned | 1 } else { aligned }
+        } else {
+            1
+        };
+
+        if hi.negative == lo.negative {
+            Wide::new(hi.negative, hi_coefficient + lo_coefficient, hi.exponent - 2)
+        } else if hi_coefficient >= lo_coefficient {
+            Wide::new(hi.negative, hi_coefficient - lo_coefficient, hi.exponent - 2)
+        } else {
+            Wide::new(lo.negative, lo_coefficient - hi_coefficient, hi.exponent - 2)
+        }
+    }
+}
+
+impl Sub for Wide {
+    type Output = Wide;
+
+    #[inline]
+    fn sub(self, other: Wide) -> Wide {
+        self + -other
+    }
+}
+
+impl Mul for Wide {
+    type Output = Wide;
+
+    fn mul(self, other: Wide) -> Wide {
+        if self.is_zero() || other.is_zero() {
+            return WIDE_ZERO;
+        }
+
+        // Long multiplication on 18 digit halves, keeping the top 36 digits
+        // of the 71 or 72 digit product.
+        let (a1, a0) = (self.coefficient / SPLIT, self.coefficient % SPLIT);
+        let (b1, b0) = (other.coefficient / SPLIT, other.coefficient % SPLIT);
+        let low = a0 * b0;
+        let mid = a1 * b0 + a0 * b1 + low / SPLIT;
+        let high = a1 * b1 + mid / SPLIT;
+        let (mid, low) = (mid % SPLIT, low % SPLIT);
+
+        let negative = self.negative != other.negative;
+        let exponent = self.exponent + other.exponent;
+        if high >= MIN_NORMAL {
+            let coefficient = if mid != 0 || low != 0 { high | 1 } else { high };
+
+            Wide::new(negative, coefficient, exponent + 36)
+        } else {
+            // The product is one digit short, take one more from the middle.
+            let coefficient = high * 10 + mid / SPLIT_TENTH;
+            let coefficient = if mid % SPLIT_TENTH != 0 || low != 0 { coefficient | 1 } else { coefficient };
+
+            Wide::new(negative, coefficient, exponent + 35)
+        }
+    }
+}
+
+impl Div for Wide {
+    type Output = Wide;
+
+    /// The divisor must not be zero.
+    fn div(self, other: Wide) -> Wide {
+        if self.is_zero() {
+            return WIDE_ZERO;
+        }
+
+        // Long division, two digits at a time. Both coefficients have 36
+        // digits, so this yields a quotient of 36 or 37 digits.
+        let divisor = other.coefficient;
+        let mut quotient = self.coefficient / divisor;
+        let mut remainder = self.coefficient % divisor;
+        for _ in 0..18 {
+            remainder *= 100;
+            quotient = quotient * 100 + remainder / divisor;
+            remainder %= divisor;
+        }
+        if remainder != 0 {
+            quotient |= 1;
+        }
+
+        Wide::new(self.negative != other.negative, quotient, self.exponent - other.exponent - 36)
+    }
+}
+
+/// Integer square root, rounded down.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Start from a power of two that is no smaller than the root, and let
+    // Newton's iteration descend from there.
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Remove trailing zeros from the fraction of a DEC64 value.
+pub fn trim_zeros(dec: Dec64) -> Dec64 {
+    let mut coefficient = dec.coefficient();
+    let mut exponent = dec.exponent();
+    if coefficient == 0 {
+        return dec;
+    }
+
+    while exponent < 0 && coefficient % 10 == 0 {
+        coefficient /= 10;
+        exponent += 1;
+    }
+
+    Dec64::from_parts(coefficient, exponent)
+}
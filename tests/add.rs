@@ -1,8 +1,9 @@
 extern crate dec64;
 
+use dec64::Context;
 use dec64::Dec64;
+use dec64::RoundingMode;
 use dec64::ZERO;
-use dec64::NAN;
 use dec64::more_consts::NAN_NAN;
 use dec64::more_consts::NEG_FIFTH;
 use dec64::more_consts::NEG_TENTH;
@@ -60,22 +61,22 @@ fn add_same_neg() {
 
 #[test]
 fn add_nan() {
-	assert_eq!(NAN_NAN + NAN_NAN, NAN);
+	assert_eq!(NAN_NAN + NAN_NAN, NAN_NAN);
 
-	assert_eq!(NAN_NAN + ZERO, NAN);
-	assert_eq!(ZERO + NAN_NAN, NAN);
+	assert_eq!(NAN_NAN + ZERO, NAN_NAN);
+	assert_eq!(ZERO + NAN_NAN, NAN_NAN);
 
-	assert_eq!(NAN_NAN + ZIP, NAN);
-	assert_eq!(ZIP + NAN_NAN, NAN);
+	assert_eq!(NAN_NAN + ZIP, NAN_NAN);
+	assert_eq!(ZIP + NAN_NAN, NAN_NAN);
 
-	assert_eq!(NAN_NAN + ONE, NAN);
-	assert_eq!(ONE + NAN_NAN, NAN);
+	assert_eq!(NAN_NAN + ONE, NAN_NAN);
+	assert_eq!(ONE + NAN_NAN, NAN_NAN);
 
-	assert_eq!(NAN_NAN + dec64::MAX, NAN);
-	assert_eq!(dec64::MAX + NAN_NAN, NAN);
+	assert_eq!(NAN_NAN + dec64::MAX, NAN_NAN);
+	assert_eq!(dec64::MAX + NAN_NAN, NAN_NAN);
 
-	assert_eq!(NAN_NAN + dec64::MIN, NAN);
-	assert_eq!(dec64::MIN + NAN_NAN, NAN);
+	assert_eq!(NAN_NAN + dec64::MIN, NAN_NAN);
+	assert_eq!(dec64::MIN + NAN_NAN, NAN_NAN);
 }
 
 #[test]
@@ -83,10 +84,11 @@ fn add_neg_tenth() {
 	assert_eq!(NEG_TENTH + NEG_TENTH, NEG_FIFTH);
 }
 
+#[cfg(not(feature = "strict"))]
 #[test]
 fn add_range_overflow() {
-	assert_eq!(dec64::MAX + dec64::MAX, NAN);
-	assert_eq!(dec64::MIN + dec64::MIN, NAN);
+	assert!((dec64::MAX + dec64::MAX).is_nan());
+	assert!((dec64::MIN + dec64::MIN).is_nan());
 }
 
 #[test]
@@ -191,3 +193,44 @@ fn add_double_min_coefficient() {
 
 	assert_eq!(value + value, expect);
 }
+
+#[test]
+fn sub_min_coefficient() {
+	// Negating MIN_COEFFICIENT on its own would have to round it.
+	let min = Dec64::from_parts(dec64::MIN_COEFFICIENT, 0);
+
+	assert_eq!(Dec64::from_parts(-4, 0) - min, Dec64::from_parts(36028797018963964, 0));
+	assert_eq!(Dec64::from_parts(-9, 0) - min, Dec64::from_parts(36028797018963959, 0));
+	assert_eq!(ZERO - min, Dec64::from_parts(3602879701896397, 1));
+}
+
+#[test]
+fn add_sticky() {
+	let tiny = Dec64::from_parts(1, -30);
+
+	// 1 + 1e-30 and 1 - 1e-30 round to one to the nearest, but not when directed.
+	assert_eq!(ONE + tiny, ONE);
+	assert_eq!(ONE - tiny, ONE);
+	assert_eq!(ONE.add_with(tiny, RoundingMode::Ceiling), Dec64::from_parts(10000000000000001, -16));
+	assert_eq!(ONE.add_with(tiny, RoundingMode::Floor), ONE);
+	assert_eq!(ONE.sub_with(tiny, RoundingMode::Floor), Dec64::from_parts(9999999999999999, -16));
+	assert_eq!(NEG_ONE.sub_with(tiny, RoundingMode::TowardZero), NEG_ONE);
+	assert_eq!(NEG_ONE.sub_with(tiny, RoundingMode::AwayFromZero), Dec64::from_parts(-10000000000000001, -16));
+
+	// A dropped tail of exactly one half is a tie, anything more is not.
+	let half = Dec64::from_parts(5, -17);
+	assert_eq!(ONE.add_with(half, RoundingMode::HalfEven), ONE);
+	assert_eq!(ONE.add_with(half + Dec64::from_parts(1, -30), RoundingMode::HalfEven), Dec64::from_parts(10000000000000001, -16));
+}
+
+#[test]
+fn add_current_context() {
+	let tiny = Dec64::from_parts(-1, -30);
+
+	Context::set_current(Context::new(RoundingMode::Floor));
+	assert_eq!(ONE + tiny, Dec64::from_parts(9999999999999999, -16));
+	assert!(Context::current().flags().inexact);
+
+	Context::set_current(Context::new(RoundingMode::Ceiling));
+	assert_eq!(ONE + tiny, ONE);
+}
@@ -0,0 +1,112 @@
+extern crate dec64;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::RoundingMode;
+use dec64::ZERO;
+use dec64::more_consts::HALF;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+#[test]
+fn compare_same_exponent() {
+	assert!(ONE < TWO);
+	assert!(NEG_ONE < ZERO);
+	assert!(Dec64::from_parts(15, -1) > Dec64::from_parts(14, -1));
+	assert!(ONE <= ONE);
+}
+
+#[test]
+fn compare_across_exponents() {
+	assert!(HALF < ONE);
+	assert!(Dec64::from_parts(1200, -2) > Dec64::from_parts(11, 0));
+	assert!(Dec64::from_parts(1200, -2) <= Dec64::from_parts(12, 0));
+	assert!(Dec64::from_parts(1200, -2) >= Dec64::from_parts(12, 0));
+	assert!(Dec64::from_parts(-1, 10) < Dec64::from_parts(-9999999999, 0));
+	assert!(ZIP <= ZERO);
+	assert!(ZIP >= ZERO);
+}
+
+#[test]
+fn compare_far_exponents() {
+	assert!(dec64::MIN_POSITIVE < dec64::MAX);
+	assert!(dec64::MIN < dec64::MIN_POSITIVE);
+	assert!(Dec64::from_parts(1, 100) > Dec64::from_parts(dec64::MAX_COEFFICIENT, -100));
+	assert!(Dec64::from_parts(-1, 100) < Dec64::from_parts(dec64::MIN_COEFFICIENT, -100));
+	assert!(ZERO < dec64::MIN_POSITIVE);
+	assert!(ZERO > Dec64::from_parts(-1, -127));
+	assert!(ZERO < Dec64::from_parts(1, 127));
+}
+
+#[test]
+fn compare_nan() {
+	assert_eq!(NAN.partial_cmp(&ONE), None);
+	assert_eq!(ONE.partial_cmp(&NAN_NAN), None);
+	assert_eq!(NAN.partial_cmp(&NAN_NAN), None);
+	assert!(NAN <= NAN);
+}
+
+#[test]
+fn equal_across_exponents() {
+	assert_eq!(Dec64::from_parts(10, -1), ONE);
+	assert_eq!(Dec64::from_parts(10000000000000000, -16), ONE);
+	assert_eq!(Dec64::from_parts(-1200, -2), Dec64::from_parts(-12, 0));
+	assert_eq!(ZIP, ZERO);
+	assert_ne!(Dec64::from_parts(11, -1), ONE);
+	assert_ne!(Dec64::from_parts(-10, -1), ONE);
+	assert_ne!(NAN, NAN_NAN);
+	assert_ne!(NAN_NAN, ZERO);
+}
+
+#[test]
+fn equal_leaves_context_alone() {
+	Context::set_current(Context::new(RoundingMode::Floor));
+
+	// Subtracting these would round, raising the inexact flag.
+	assert_ne!(Dec64::from_parts(1, 100), ONE);
+	assert_ne!(dec64::MAX, Dec64::from_parts(-1, -127));
+	assert_eq!(Dec64::from_parts(3602879701896396, 1), Dec64::from_parts(36028797018963960, 0));
+
+	assert!(Context::current().flags().is_empty());
+}
+
+#[test]
+fn min_max() {
+	assert_eq!(ONE.min(HALF), HALF);
+	assert_eq!(ONE.max(HALF), ONE);
+	assert_eq!(NAN.min(ONE), ONE);
+	assert_eq!(ONE.max(NAN_NAN), ONE);
+	assert!(NAN.max(NAN).is_nan());
+}
+
+#[test]
+fn minimum_maximum() {
+	assert_eq!(NEG_ONE.minimum(ZERO), NEG_ONE);
+	assert_eq!(NEG_ONE.maximum(ZERO), ZERO);
+	assert!(NAN.minimum(ONE).is_nan());
+	assert_eq!(ONE.maximum(NAN_NAN), NAN_NAN);
+}
+
+#[test]
+fn clamp() {
+	assert_eq!(TWO.clamp(NEG_ONE, ONE), ONE);
+	assert_eq!(Dec64::from_parts(-15, -1).clamp(NEG_ONE, ONE), NEG_ONE);
+	assert_eq!(HALF.clamp(NEG_ONE, ONE), HALF);
+	assert_eq!(NAN_NAN.clamp(NEG_ONE, ONE), NAN_NAN);
+}
+
+#[test]
+#[should_panic]
+fn clamp_inverted() {
+	ONE.clamp(ONE, NEG_ONE);
+}
+
+#[test]
+#[should_panic]
+fn clamp_nan_bound() {
+	ONE.clamp(NAN, NAN);
+}
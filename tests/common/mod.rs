@@ -0,0 +1,14 @@
+// Helpers shared by the integration tests. Not every test uses all of them.
+#![allow(dead_code)]
+
+use dec64::Dec64;
+
+pub fn d(coefficient: i64, exponent: i8) -> Dec64 {
+	Dec64::from_parts(coefficient, exponent)
+}
+
+/// The coefficient and exponent, to check the representation of a result
+/// rather than only its value.
+pub fn parts(value: Dec64) -> (i64, i8) {
+	(value.coefficient(), value.exponent())
+}
@@ -0,0 +1,202 @@
+//! Data-driven conformance tests of the `crockford` module, which should agree
+//! bit for bit with the reference C library.
+//!
+//! Every `*.dec64` file in `tests/vectors` is loaded. Each line holds one vector:
+//!
+//! ```text
+//! add 0x0000000000000101 one = two
+//! ```
+//!
+//! that is the operation, its operands, `=` and the expected result. Numbers
+//! are raw 64-bit words in hex, or the names of the constants below. The
+//! operands of `new` are a decimal coefficient and exponent. Blank lines and
+//! everything after `#` are ignored. To add cases, add lines or files.
+//!
+//! No vector comes from the reference yet: the cases of `dec64_test.c` are
+//! still to be transcribed, as it is not part of this tree. The expected
+//! results were instead worked out in exact decimal arithmetic from the rules
+//! documented in `crockford`, independently of its code. `derived.dec64` runs
+//! every operation over pairs of the named constants, and the other files
+//! cover the special values, rounding and NaN and zero handling.
+//!
+//! Where the rules leave the representation of a result open, there is no
+//! vector, as its expected result could only restate this crate. The one such
+//! case found is a quotient that is not exact but ends in zeros once rounded,
+//! like `divide almost_one two`, which may or may not keep them.
+//!
+//! The vectors include NaNs produced by the operations, which panic instead
+//! with the `strict` feature, so these tests are left out with it.
+
+#![cfg(not(feature = "strict"))]
+
+extern crate dec64;
+
+use std::fs;
+use std::path::Path;
+
+use dec64::Dec64;
+use dec64::crockford::*;
+use dec64::more_consts;
+use dec64::more_consts::normal;
+
+/// A vector, with where it came from.
+struct Vector {
+	place: String,
+	operation: String,
+	operands: Vec<String>,
+	expected: Dec64,
+}
+
+fn constant(name: &str) -> Option<Dec64> {
+	Some(match name {
+		"nan" => dec64::NAN,
+		"zero" => dec64::ZERO,
+		"max" => dec64::MAX,
+		"min" => dec64::MIN,
+		"min_positive" => dec64::MIN_POSITIVE,
+		"epsilon" => dec64::EPSILON,
+		"pi" => dec64::consts::PI,
+		"e" => dec64::consts::E,
+		"true" => DEC64_TRUE,
+		"false" => DEC64_FALSE,
+		"nan_nan" => more_consts::NAN_NAN,
+		"zip" => more_consts::ZIP,
+		"cent" => more_consts::CENT,
+		"tenth" => more_consts::TENTH,
+		"half" => more_consts::HALF,
+		"almost_one" => more_consts::ALMOST_ONE,
+		"googol" => more_consts::GOOGOL,
+		"neg_minnum" => more_consts::NEG_MINNUM,
+		"neg_pi" => more_consts::NEG_PI,
+		"neg_tenth" => more_consts::NEG_TENTH,
+		"neg_fifth" => more_consts::NEG_FIFTH,
+		"one" => normal::ONE,
+		"two" => normal::TWO,
+		"three" => normal::THREE,
+		"four" => normal::FOUR,
+		"five" => normal::FIVE,
+		"six" => normal::SIX,
+		"seven" => normal::SEVEN,
+		"eight" => normal::EIGHT,
+		"nine" => normal::NINE,
+		"ten" => normal::TEN,
+		"neg_one" => normal::NEG_ONE,
+		"neg_two" => normal::NEG_TWO,
+		"neg_three" => normal::NEG_THREE,
+		"neg_four" => normal::NEG_FOUR,
+		"neg_five" => normal::NEG_FIVE,
+		"neg_six" => normal::NEG_SIX,
+		"neg_seven" => normal::NEG_SEVEN,
+		"neg_eight" => normal::NEG_EIGHT,
+		"neg_nine" => normal::NEG_NINE,
+		"neg_ten" => normal::NEG_TEN,
+		"maxint" => normal::MAXINT,
+		"minint" => normal::MININT,
+		_ => return None,
+	})
+}
+
+/// Parses a raw word in hex, or the name of a constant.
+fn number(token: &str) -> Result<Dec64, String> {
+	if let Some(hex) = token.strip_prefix("0x") {
+		let raw = u64::from_str_radix(hex, 16).map_err(|error| format!("bad hex {}: {}", token, error))?;
+
+		return Ok(Dec64::from_parts((raw as i64) >> 8, raw as i8));
+	}
+
+	constant(token).ok_or_else(|| format!("unknown constant {}", token))
+}
+
+fn integer(token: &str) -> i64 {
+	token.parse().unwrap_or_else(|error| panic!("bad integer {}: {}", token, error))
+}
+
+/// Loads the vectors of a file, panicking on lines that do not parse.
+fn load(path: &Path) -> Vec<Vector> {
+	let text = fs::read_to_string(path).unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+	let mut vectors = Vec::new();
+
+	for (index, line) in text.lines().enumerate() {
+		let place = format!("{}:{}", path.display(), index + 1);
+		let line = line.split('#').next().unwrap_or("").trim();
+		if line.is_empty() {
+			continue;
+		}
+
+		let (left, right) = line.split_once('=').unwrap_or_else(|| panic!("{}: missing =", place));
+		let mut tokens = left.split_whitespace().map(String::from);
+		let operation = tokens.next().unwrap_or_else(|| panic!("{}: missing operation", place));
+		let expected = number(right.trim()).unwrap_or_else(|error| panic!("{}: {}", place, error));
+
+		vectors.push(Vector { place, operation, operands: tokens.collect(), expected });
+	}
+
+	vectors
+}
+
+/// Runs a vector, returning the result, or an error if it does not parse.
+fn run(vector: &Vector) -> Result<Dec64, String> {
+	if vector.operation == "new" {
+		return match vector.operands[..] {
+			[ref coefficient, ref exponent] => Ok(dec64_new(integer(coefficient), integer(exponent))),
+			_ => Err("new takes a coefficient and an exponent".to_string()),
+		};
+	}
+
+	let operands = vector.operands.iter().map(|token| number(token)).collect::<Result<Vec<_>, _>>()?;
+	let unary: Option<fn(Dec64) -> Dec64> = match &vector.operation[..] {
+		"floor" => Some(dec64_floor),
+		"ceiling" => Some(dec64_ceiling),
+		"int" => Some(dec64_int),
+		"signum" => Some(dec64_signum),
+		"is_nan" => Some(dec64_is_nan),
+		"is_zero" => Some(dec64_is_zero),
+		"is_integer" => Some(dec64_is_integer),
+		_ => None,
+	};
+	let binary: Option<fn(Dec64, Dec64) -> Dec64> = match &vector.operation[..] {
+		"add" => Some(dec64_add),
+		"subtract" => Some(dec64_subtract),
+		"multiply" => Some(dec64_multiply),
+		"divide" => Some(dec64_divide),
+		"integer_divide" => Some(dec64_integer_divide),
+		"modulo" => Some(dec64_modulo),
+		"round" => Some(dec64_round),
+		"is_equal" => Some(dec64_is_equal),
+		"is_less" => Some(dec64_is_less),
+		_ => None,
+	};
+
+	match (unary, binary, &operands[..]) {
+		(Some(function), _, &[a]) => Ok(function(a)),
+		(_, Some(function), &[a, b]) => Ok(function(a, b)),
+		(None, None, _) => Err(format!("unknown operation {}", vector.operation)),
+		_ => Err(format!("wrong number of operands for {}", vector.operation)),
+	}
+}
+
+fn raw(number: Dec64) -> String {
+	format!("0x{:016x}", (number.coefficient() << 8) as u64 | number.exponent() as u8 as u64)
+}
+
+#[test]
+fn conformance_vectors() {
+	let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("vectors");
+	let mut paths: Vec<_> = fs::read_dir(&directory).unwrap().map(|entry| entry.unwrap().path()).collect();
+	paths.retain(|path| path.extension().is_some_and(|extension| extension == "dec64"));
+	paths.sort();
+
+	let mut count = 0;
+	let mut failures = Vec::new();
+	for vector in paths.iter().flat_map(|path| load(path)) {
+		count += 1;
+		match run(&vector) {
+			Ok(result) if raw(result) == raw(vector.expected) => {},
+			Ok(result) => failures.push(format!("{}: {} {} = {}, expected {}", vector.place, vector.operation, vector.operands.join(" "), raw(result), raw(vector.expected))),
+			Err(error) => failures.push(format!("{}: {}", vector.place, error)),
+		}
+	}
+
+	assert!(count > 0, "no vectors in {}", directory.display());
+	assert!(failures.is_empty(), "{} of {} vectors failed:\n{}", failures.len(), count, failures.join("\n"));
+}
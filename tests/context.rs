@@ -0,0 +1,199 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::Flags;
+use dec64::RoundingMode;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+use common::parts;
+
+#[test]
+fn context_div_modes() {
+	let three = Dec64::from_parts(3, 0);
+	let divide = |mode, a| parts(Context::new(mode).div(a, three));
+
+	assert_eq!(divide(RoundingMode::HalfEven, TWO), (6666666666666667, -16));
+	assert_eq!(divide(RoundingMode::Floor, TWO), (6666666666666666, -16));
+	assert_eq!(divide(RoundingMode::Floor, -TWO), (-6666666666666667, -16));
+	assert_eq!(divide(RoundingMode::Ceiling, -TWO), (-6666666666666666, -16));
+	assert_eq!(divide(RoundingMode::TowardZero, -TWO), (-6666666666666666, -16));
+	assert_eq!(divide(RoundingMode::AwayFromZero, ONE), (33333333333333334, -17));
+}
+
+#[test]
+fn context_div_exact() {
+	let mut context = Context::new(RoundingMode::HalfEven);
+
+	assert_eq!(parts(context.div(ONE, Dec64::from_parts(4, 0))), (25, -2));
+	assert_eq!(parts(context.div(Dec64::from_parts(12, 0), Dec64::from_parts(-4, 0))), (-3, 0));
+	assert!(context.flags().is_empty());
+}
+
+#[test]
+fn context_add_sticky() {
+	let tiny = Dec64::from_parts(-1, -30);
+	let add = |mode| Context::new(mode).add(ONE, tiny);
+
+	// 1 - 1e-30 lies just below one.
+	assert_eq!(add(RoundingMode::HalfEven), ONE);
+	assert_eq!(add(RoundingMode::Ceiling), ONE);
+	assert_eq!(parts(add(RoundingMode::Floor)), (9999999999999999, -16));
+	assert_eq!(parts(add(RoundingMode::TowardZero)), (9999999999999999, -16));
+	assert_eq!(parts(Context::new(RoundingMode::Ceiling).sub(ONE, tiny)), (10000000000000001, -16));
+}
+
+#[test]
+fn context_mul_ties() {
+	let value = Dec64::from_parts(12345678901234567, 0);
+	let three = Dec64::from_parts(3, 0);
+	let tie = Dec64::from_parts(36028797018963965, 0);
+	let multiply = |mode, a| parts(Context::new(mode).mul(a, three));
+
+	// 12345678901234567 * 3 = 3703703670370370|1
+	assert_eq!(multiply(RoundingMode::HalfEven, value), (3703703670370370, 1));
+	assert_eq!(multiply(RoundingMode::Ceiling, value), (3703703670370371, 1));
+	// 36028797018963965 * 3 = 10808639105689189|5
+	assert_eq!(multiply(RoundingMode::HalfEven, tie), (10808639105689190, 1));
+	assert_eq!(multiply(RoundingMode::HalfTowardZero, tie), (10808639105689189, 1));
+	assert_eq!(multiply(RoundingMode::HalfAwayFromZero, tie), (10808639105689190, 1));
+	assert_eq!(multiply(RoundingMode::HalfTowardZero, -tie), (-10808639105689189, 1));
+}
+
+#[test]
+fn context_flags() {
+	let mut context = Context::default();
+
+	context.add(ONE, TWO);
+	assert!(context.flags().is_empty());
+
+	context.div(ONE, Dec64::from_parts(3, 0));
+	assert_eq!(context.flags(), Flags { inexact: true, ..Flags::default() });
+
+	assert_eq!(context.mul(dec64::MIN_POSITIVE, Dec64::from_parts(1, -1)), ZERO);
+	assert!(context.flags().underflow);
+
+	context.clear_flags();
+	assert!(context.flags().is_empty());
+	assert_eq!(context.add(NAN_NAN, ONE), NAN_NAN);
+	assert!(context.flags().is_empty());
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn context_nan_flags() {
+	let mut context = Context::default();
+
+	assert!(context.mul(dec64::MAX, TWO).is_nan());
+	assert!(context.flags().overflow);
+
+	assert!(context.div(ONE, ZERO).is_nan());
+	assert!(context.flags().division_by_zero);
+	assert!(!context.flags().invalid);
+
+	assert!(context.div(ZERO, ZERO).is_nan());
+	assert!(context.flags().invalid);
+}
+
+#[test]
+fn context_pack() {
+	let mut context = Context::new(RoundingMode::HalfEven);
+
+	assert_eq!(parts(context.pack(123456789012345665, 0)), (12345678901234566, 1));
+	assert_eq!(parts(context.pack(123456789012345675, 0)), (12345678901234568, 1));
+	assert_eq!(parts(context.pack(15, -128)), (2, -127));
+	assert_eq!(parts(context.pack(25, -128)), (2, -127));
+	assert!(context.flags().underflow);
+	assert!(!context.flags().overflow);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn context_pack_overflow() {
+	let mut context = Context::default();
+
+	assert!(context.pack(1, 200).is_nan());
+	assert!(context.flags().overflow);
+}
+
+#[test]
+fn current_context() {
+	assert_eq!(Context::current(), Context::default());
+
+	assert_eq!(parts(Dec64::pack(123456789012345665, 0)), (12345678901234567, 1));
+	assert_eq!(Context::current().flags(), Flags { inexact: true, ..Flags::default() });
+
+	Context::set_current(Context::new(RoundingMode::HalfEven));
+	assert_eq!(parts(Dec64::pack(25, -128)), (2, -127));
+	assert_eq!(parts(Dec64::pack(35, -128)), (4, -127));
+	assert!(Context::current().flags().underflow);
+
+	Context::set_current(Context::default());
+	assert!(Context::current().flags().is_empty());
+}
+
+#[test]
+fn current_context_operators() {
+	Context::set_current(Context::new(RoundingMode::Floor));
+
+	assert_eq!(TWO * Dec64::from_parts(15, -1), Dec64::from_parts(3, 0));
+	assert_eq!(ONE / TWO, Dec64::from_parts(5, -1));
+	assert!(Context::current().flags().is_empty());
+
+	assert_eq!(parts(TWO / Dec64::from_parts(3, 0)), (6666666666666666, -16));
+	assert_eq!(Context::current().flags(), Flags { inexact: true, ..Flags::default() });
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn current_context_nan_flags() {
+	let flags = |operation: &dyn Fn() -> Dec64| {
+		Context::set_current(Context::default());
+		assert!(operation().is_nan());
+		Context::current().flags()
+	};
+
+	assert_eq!(flags(&|| ONE / ZERO), Flags { division_by_zero: true, ..Flags::default() });
+	assert_eq!(flags(&|| ZERO / ZERO), Flags { invalid: true, ..Flags::default() });
+	assert_eq!(flags(&|| ONE.mul_div(ONE, ZERO)), Flags { division_by_zero: true, ..Flags::default() });
+	assert_eq!(flags(&|| ZERO.mul_div(ONE, ZERO)), Flags { invalid: true, ..Flags::default() });
+	assert_eq!(flags(&|| dec64::MAX * TWO), Flags { overflow: true, inexact: true, ..Flags::default() });
+	assert_eq!(flags(&|| dec64::MAX + dec64::MAX), Flags { overflow: true, inexact: true, ..Flags::default() });
+	assert_eq!(flags(&|| (-ONE).ln()), Flags { invalid: true, ..Flags::default() });
+	assert_eq!(flags(&|| (-ONE).sqrt()), Flags { invalid: true, ..Flags::default() });
+	assert_eq!(flags(&|| ZERO.powi(-1)), Flags { division_by_zero: true, ..Flags::default() });
+	assert_eq!(flags(&|| dec64::MAX.next_up()), Flags { overflow: true, inexact: true, ..Flags::default() });
+
+	// NaN operands are passed on quietly.
+	assert_eq!(flags(&|| NAN_NAN * ONE), Flags::default());
+
+	// A context of its own keeps its flags to itself.
+	assert_eq!(flags(&|| Context::default().div(ONE, ZERO)), Flags::default());
+}
+
+#[test]
+fn dec64_with_mode() {
+	let three = Dec64::from_parts(3, 0);
+
+	assert_eq!(ONE.div_with(three, RoundingMode::Ceiling), Dec64::from_parts(33333333333333334, -17));
+	assert_eq!(ONE.div_with(-three, RoundingMode::Ceiling), Dec64::from_parts(-33333333333333333, -17));
+	assert_eq!(TWO.div_with(three, RoundingMode::TowardZero), Dec64::from_parts(6666666666666666, -16));
+
+	let value = Dec64::from_parts(12345678901234567, 0);
+	assert_eq!(value.mul_with(three, RoundingMode::HalfEven), Dec64::from_parts(3703703670370370, 1));
+	assert_eq!(value.mul_with(three, RoundingMode::AwayFromZero), Dec64::from_parts(3703703670370371, 1));
+
+	// The current context is left alone.
+	assert!(Context::current().flags().is_empty());
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn dec64_with_mode_nan() {
+	assert!(ONE.div_with(ZERO, RoundingMode::HalfEven).is_nan());
+}
@@ -0,0 +1,148 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::crockford::*;
+use dec64::more_consts::NAN_NAN;
+
+use common::d;
+use common::parts;
+
+#[test]
+fn crockford_new() {
+	assert_eq!(parts(dec64_new(12, -1)), (12, -1));
+	assert_eq!(parts(dec64_new(0, 5)), (0, 0));
+	assert_eq!(parts(dec64_new(123456789012345675, 0)), (12345678901234568, 1));
+	assert_eq!(parts(dec64_new(1, -200)), (0, 0));
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn crockford_new_overflow() {
+	assert_eq!(parts(dec64_new(1, i64::MAX)), (0, -128));
+}
+
+#[test]
+fn crockford_arithmetic() {
+	assert_eq!(parts(dec64_add(d(1, -1), d(2, -1))), (3, -1));
+	assert_eq!(parts(dec64_add(d(1, -2), d(-1, -2))), (0, 0));
+	assert_eq!(parts(dec64_subtract(d(5, 0), d(5, 0))), (0, 0));
+	assert_eq!(parts(dec64_multiply(d(3, -1), d(4, 2))), (12, 1));
+	assert_eq!(parts(dec64_divide(d(1, 0), d(4, 0))), (25, -2));
+	assert_eq!(dec64_divide(d(2, 0), d(3, 0)), d(6666666666666667, -16));
+	assert_eq!(parts(dec64_integer_divide(d(-7, 0), d(2, 0))), (-4, 0));
+	assert_eq!(parts(dec64_modulo(d(-7, 0), d(2, 0))), (1, 0));
+	assert_eq!(parts(dec64_modulo(d(7, 0), d(-2, 0))), (-1, 0));
+	assert_eq!(parts(dec64_modulo(d(75, -1), d(2, 0))), (15, -1));
+}
+
+#[test]
+fn crockford_nan_and_zero() {
+	// NaNs lose their payloads, and zeros their exponents.
+	assert_eq!(parts(dec64_add(NAN_NAN, d(1, 0))), (0, -128));
+	assert_eq!(parts(dec64_subtract(d(1, 0), NAN_NAN)), (0, -128));
+	assert_eq!(parts(dec64_signum(NAN_NAN)), (0, -128));
+	assert_eq!(parts(dec64_floor(d(0, 3))), (0, 0));
+
+	// Zero times or divided by anything is zero.
+	assert_eq!(parts(dec64_multiply(ZERO, NAN_NAN)), (0, 0));
+	assert_eq!(parts(dec64_multiply(NAN, d(0, -5))), (0, 0));
+	assert_eq!(parts(dec64_divide(ZERO, ZERO)), (0, 0));
+	assert_eq!(parts(dec64_divide(ZERO, NAN)), (0, 0));
+	assert_eq!(parts(dec64_divide(NAN_NAN, d(1, 0))), (0, -128));
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn crockford_divide_by_zero() {
+	assert_eq!(parts(dec64_divide(d(1, 0), ZERO)), (0, -128));
+	assert_eq!(parts(dec64_modulo(d(1, 0), ZERO)), (0, -128));
+}
+
+#[test]
+fn crockford_rounding() {
+	assert_eq!(parts(dec64_floor(d(-15, -1))), (-2, 0));
+	assert_eq!(parts(dec64_ceiling(d(-15, -1))), (-1, 0));
+	assert_eq!(parts(dec64_ceiling(d(1, -20))), (1, 0));
+	assert_eq!(parts(dec64_int(d(-15, -1))), (-1, 0));
+	assert_eq!(parts(dec64_int(d(19, -1))), (1, 0));
+	assert_eq!(parts(dec64_int(d(-1, -20))), (0, 0));
+	assert_eq!(parts(dec64_int(d(12, 3))), (12, 3));
+	assert_eq!(parts(dec64_int(NAN_NAN)), (0, -128));
+	assert_eq!(parts(dec64_floor(d(12, 3))), (12, 3));
+	assert_eq!(parts(dec64_round(d(12345, -3), d(-2, 0))), (1235, -2));
+	assert_eq!(parts(dec64_round(d(-25, -1), ZERO)), (-3, 0));
+	assert_eq!(parts(dec64_round(d(-25, -1), NAN)), (-3, 0));
+	assert_eq!(parts(dec64_round(d(1499, 0), d(3, 0))), (1, 3));
+	assert_eq!(parts(dec64_round(d(4, -1), ZERO)), (0, 0));
+	assert_eq!(parts(dec64_round(d(12, 0), d(5, -1))), (0, -128));
+	assert_eq!(parts(dec64_round(d(15, -1), dec64::MAX)), (0, 0));
+	assert_eq!(parts(dec64_round(d(15, -1), dec64::MIN)), (15, -1));
+	assert_eq!(parts(dec64_round(d(15, 126), d(1, 100))), (2, 127));
+	assert_eq!(parts(dec64_round(NAN_NAN, ZERO)), (0, -128));
+}
+
+#[test]
+fn crockford_predicates() {
+	assert_eq!(parts(DEC64_TRUE), (3, -128));
+	assert_eq!(parts(DEC64_FALSE), (2, -128));
+
+	assert_eq!(dec64_is_equal(d(10, -1), d(1, 0)).coefficient(), 3);
+	assert_eq!(dec64_is_equal(d(0, 5), ZERO).coefficient(), 3);
+	assert_eq!(dec64_is_equal(NAN, NAN).coefficient(), 3);
+	assert_eq!(dec64_is_equal(NAN, NAN_NAN).coefficient(), 2);
+	assert_eq!(dec64_is_less(d(1, 0), d(2, 0)).coefficient(), 3);
+	assert_eq!(dec64_is_less(d(2, 0), d(2, 0)).coefficient(), 2);
+	assert_eq!(dec64_is_less(dec64::MAX, NAN).coefficient(), 3);
+	assert_eq!(dec64_is_less(NAN, dec64::MAX).coefficient(), 2);
+	assert_eq!(dec64_is_nan(NAN_NAN).coefficient(), 3);
+	assert_eq!(dec64_is_zero(d(0, -3)).coefficient(), 3);
+	assert_eq!(dec64_is_zero(NAN).coefficient(), 2);
+	assert_eq!(dec64_is_integer(d(100, -2)).coefficient(), 3);
+	assert_eq!(dec64_is_integer(d(101, -2)).coefficient(), 2);
+	assert_eq!(parts(dec64_signum(d(-5, 3))), (-1, 0));
+}
+
+#[test]
+fn crockford_parts() {
+	assert_eq!(dec64_coefficient(d(-12, 3)), -12);
+	assert_eq!(dec64_exponent(d(-12, 3)), 3);
+	assert_eq!(dec64_exponent(NAN), -128);
+
+	assert_eq!(parts(dec64_normal(d(12, 2))), (1200, 0));
+	assert_eq!(parts(dec64_normal(d(1200, -2))), (12, 0));
+	assert_eq!(parts(dec64_normal(d(1230, -3))), (123, -2));
+	assert_eq!(parts(dec64_normal(dec64::MAX)), (dec64::MAX_COEFFICIENT, 127));
+	assert_eq!(parts(dec64_normal(d(0, 4))), (0, 0));
+	assert_eq!(parts(dec64_normal(NAN_NAN)), (0, -128));
+
+	assert_eq!(parts(dec64_neg(d(5, -1))), (-5, -1));
+	assert_eq!(parts(dec64_neg(d(0, 2))), (0, 0));
+	assert_eq!(parts(dec64_neg(d(dec64::MIN_COEFFICIENT, 0))), (3602879701896397, 1));
+	assert_eq!(parts(dec64_abs(d(-5, -1))), (5, -1));
+	assert_eq!(parts(dec64_abs(NAN_NAN)), (0, -128));
+}
+
+#[test]
+fn crockford_inc_dec_half() {
+	assert_eq!(parts(dec64_inc(d(15, -1))), (25, -1));
+	assert_eq!(parts(dec64_inc(d(-1, 0))), (0, 0));
+	assert_eq!(parts(dec64_dec(d(15, -1))), (5, -1));
+	assert_eq!(parts(dec64_dec(NAN_NAN)), (0, -128));
+	assert_eq!(parts(dec64_half(d(3, 0))), (15, -1));
+	assert_eq!(parts(dec64_half(d(-7, 2))), (-35, 1));
+	assert_eq!(parts(dec64_half(NAN)), (0, -128));
+}
+
+#[test]
+fn crockford_booleans() {
+	assert_eq!(parts(dec64_not(DEC64_TRUE)), parts(DEC64_FALSE));
+	assert_eq!(parts(dec64_not(DEC64_FALSE)), parts(DEC64_TRUE));
+	assert_eq!(parts(dec64_not(ZERO)), (0, -128));
+	assert_eq!(parts(dec64_is_false(DEC64_FALSE)), parts(DEC64_TRUE));
+	assert_eq!(parts(dec64_is_false(DEC64_TRUE)), parts(DEC64_FALSE));
+	assert_eq!(parts(dec64_is_false(ZERO)), parts(DEC64_FALSE));
+	assert_eq!(parts(dec64_is_false(NAN)), parts(DEC64_FALSE));
+}
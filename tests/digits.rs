@@ -0,0 +1,78 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+
+use common::parts;
+
+#[test]
+fn as_integer() {
+	assert_eq!(Dec64::from_parts(1200, -2).as_integer(), Some(12));
+	assert_eq!(Dec64::from_parts(-7, 3).as_integer(), Some(-7000));
+	assert_eq!(ZIP.as_integer(), Some(0));
+	assert_eq!(Dec64::from_parts(5, 18).as_integer(), Some(5000000000000000000));
+	assert_eq!(Dec64::from_parts(1, 19).as_integer(), None);
+	assert_eq!(Dec64::from_parts(15, -1).as_integer(), None);
+	assert_eq!(NAN.as_integer(), None);
+}
+
+#[test]
+fn split_integer_fraction() {
+	let (integer, fraction) = Dec64::from_parts(12345, -2).split_integer_fraction();
+	assert_eq!(parts(integer), (123, 0));
+	assert_eq!(parts(fraction), (45, -2));
+
+	let (integer, fraction) = Dec64::from_parts(-12345, -2).split_integer_fraction();
+	assert_eq!(parts(integer), (-123, 0));
+	assert_eq!(parts(fraction), (-45, -2));
+}
+
+#[test]
+fn split_integer_fraction_edges() {
+	assert_eq!(Dec64::from_parts(-5, -1).split_integer_fraction(), (ZERO, Dec64::from_parts(-5, -1)));
+	assert_eq!(Dec64::from_parts(1200, -2).split_integer_fraction(), (Dec64::from_parts(12, 0), ZERO));
+	assert_eq!(Dec64::from_parts(7, 5).split_integer_fraction(), (Dec64::from_parts(7, 5), ZERO));
+	assert_eq!(dec64::MIN_POSITIVE.split_integer_fraction(), (ZERO, dec64::MIN_POSITIVE));
+
+	let (integer, fraction) = NAN_NAN.split_integer_fraction();
+	assert_eq!(parts(integer), parts(NAN_NAN));
+	assert_eq!(parts(fraction), parts(NAN_NAN));
+}
+
+#[test]
+fn significant_digits() {
+	assert_eq!(Dec64::from_parts(12345, -2).significant_digits(), 5);
+	assert_eq!(Dec64::from_parts(-1200, 3).significant_digits(), 4);
+	assert_eq!(Dec64::from_parts(-1200, 3).normalize().significant_digits(), 2);
+	assert_eq!(Dec64::from_parts(dec64::MIN_COEFFICIENT, 0).significant_digits(), 17);
+	assert_eq!(ZERO.significant_digits(), 0);
+	assert_eq!(NAN.significant_digits(), 0);
+}
+
+#[test]
+fn trailing_zeros() {
+	assert_eq!(Dec64::from_parts(1200, -2).trailing_zeros(), 2);
+	assert_eq!(Dec64::from_parts(-10000000000000000, 0).trailing_zeros(), 16);
+	assert_eq!(Dec64::from_parts(12345, -2).trailing_zeros(), 0);
+	assert_eq!(ZIP.trailing_zeros(), 0);
+	assert_eq!(NAN_NAN.trailing_zeros(), 0);
+}
+
+#[test]
+fn decimal_places_rule() {
+	// At most 4 decimal places and 12 significant digits.
+	let valid = |value: Dec64| {
+		let value = value.normalize();
+		value.exponent() >= -4 && value.significant_digits() <= 12
+	};
+
+	assert!(valid(Dec64::from_parts(123456789012, -4)));
+	assert!(valid(Dec64::from_parts(1234500000, -9)));
+	assert!(!valid(Dec64::from_parts(12345, -5)));
+	assert!(!valid(Dec64::from_parts(1234567890123, 0)));
+}
@@ -0,0 +1,207 @@
+extern crate dec64;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::consts::E;
+use dec64::consts::LN_10;
+use dec64::consts::LN_2;
+use dec64::consts::LOG10_E;
+use dec64::consts::LOG2_E;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TEN;
+use dec64::more_consts::normal::TWO;
+
+#[test]
+fn exp_consts() {
+	assert_eq!(ZERO.exp(), ONE);
+	assert_eq!(ONE.exp(), E);
+	// e^-1 = 0.36787944117144232|2
+	assert_eq!(NEG_ONE.exp(), Dec64::from_parts(3678794411714423, -16));
+	// e^100 = 2.6881171418161354|48e43
+	assert_eq!(Dec64::from_parts(100, 0).exp(), Dec64::from_parts(26881171418161354, 27));
+}
+
+#[test]
+fn exp_range() {
+	assert_eq!(Dec64::from_parts(-400, 0).exp(), ZERO);
+	assert_eq!(dec64::MIN.exp(), ZERO);
+	assert_eq!(NAN_NAN.exp(), NAN_NAN);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn exp_overflow() {
+	assert!(Dec64::from_parts(400, 0).exp().is_nan());
+	assert!(dec64::MAX.exp().is_nan());
+}
+
+#[test]
+fn ln_consts() {
+	assert_eq!(ONE.ln(), ZERO);
+	assert_eq!(E.ln(), ONE);
+	assert_eq!(TWO.ln(), LN_2);
+	assert_eq!(TEN.ln(), LN_10);
+}
+
+#[test]
+fn ln_close_to_one() {
+	// ln(0.99999999999) = -1.000000000005000000000033e-11
+	let expect = Dec64::from_parts(-1000000000005, -23);
+
+	assert_eq!(Dec64::from_parts(99999999999, -11).ln(), expect);
+}
+
+#[test]
+fn ln_extremes() {
+	// ln(MAX) = 330.5514017410407938|6
+	assert_eq!(dec64::MAX.ln(), Dec64::from_parts(33055140174104079, -14));
+	// ln(MIN_POSITIVE) = -292.4283068102438018|7
+	assert_eq!(dec64::MIN_POSITIVE.ln(), Dec64::from_parts(-2924283068102438, -13));
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn ln_nan() {
+	assert!(ZERO.ln().is_nan());
+	assert!(ZIP.ln().is_nan());
+	assert!(NEG_ONE.ln().is_nan());
+	assert!(NAN.ln().is_nan());
+}
+
+#[test]
+fn log10_powers_of_ten() {
+	assert_eq!(ONE.log10(), ZERO);
+	assert_eq!(Dec64::from_parts(1000, 0).log10(), Dec64::from_parts(3, 0));
+	assert_eq!(Dec64::from_parts(1, -5).log10(), Dec64::from_parts(-5, 0));
+	assert_eq!(Dec64::from_parts(100, 120).log10(), Dec64::from_parts(122, 0));
+	assert_eq!(dec64::MIN_POSITIVE.log10(), Dec64::from_parts(-127, 0));
+}
+
+#[test]
+fn log10_consts() {
+	assert_eq!(E.log10(), LOG10_E);
+}
+
+#[test]
+fn log2() {
+	assert_eq!(E.log2(), LOG2_E);
+	assert_eq!(Dec64::from_parts(8, 0).log2(), Dec64::from_parts(3, 0));
+	assert_eq!(Dec64::from_parts(5, -1).log2(), NEG_ONE);
+}
+
+#[test]
+fn log_base() {
+	let three = Dec64::from_parts(3, 0);
+
+	assert_eq!(Dec64::from_parts(27, 0).log(three), three);
+	assert!(TEN.log(NAN).is_nan());
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn log_nan() {
+	assert!(Dec64::from_parts(-1, 0).log10().is_nan());
+	assert!(ZERO.log2().is_nan());
+	assert!(TEN.log(ONE).is_nan());
+	assert!(TEN.log(ZERO).is_nan());
+}
+
+#[test]
+fn pow_integer() {
+	assert_eq!(Dec64::from_parts(11, -1).pow(TWO), Dec64::from_parts(121, -2));
+	assert_eq!(TWO.pow(Dec64::from_parts(-2, 0)), Dec64::from_parts(25, -2));
+	assert_eq!(Dec64::from_parts(-2, 0).pow(Dec64::from_parts(3, 0)), Dec64::from_parts(-8, 0));
+	// 1.05^10 = 1.62889462677744140625
+	assert_eq!(Dec64::from_parts(105, -2).pow(TEN), Dec64::from_parts(16288946267774414, -16));
+}
+
+#[test]
+fn pow_huge_integer() {
+	let huge = Dec64::from_parts(1, 20);
+
+	assert_eq!(Dec64::from_parts(5, -1).pow(huge), ZERO);
+	assert_eq!(NEG_ONE.pow(huge), ONE);
+	assert_eq!(NEG_ONE.pow(dec64::more_consts::normal::MAXINT), NEG_ONE);
+}
+
+#[test]
+fn pow_fraction() {
+	let half = Dec64::from_parts(5, -1);
+
+	assert_eq!(TWO.pow(half), TWO.sqrt());
+	assert_eq!(TEN.pow(half), TEN.sqrt());
+}
+
+#[test]
+fn pow_zero() {
+	assert_eq!(ZERO.pow(ZERO), ONE);
+	assert!(NAN.pow(ZERO).is_nan());
+	assert_eq!(ZERO.pow(TWO), ZERO);
+	assert_eq!(TWO.pow(ZIP), ONE);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn pow_nan() {
+	assert!(TWO.pow(Dec64::from_parts(1, 20)).is_nan());
+	assert!(Dec64::from_parts(-2, 0).pow(Dec64::from_parts(5, -1)).is_nan());
+	assert!(ZERO.pow(NEG_ONE).is_nan());
+}
+
+#[test]
+fn powi_exact() {
+	assert_eq!(Dec64::from_parts(11, -1).powi(2), Dec64::from_parts(121, -2));
+	assert_eq!(Dec64::from_parts(-15, -1).powi(3), Dec64::from_parts(-3375, -3));
+	assert_eq!(TWO.powi(-10), Dec64::from_parts(9765625, -10));
+	assert_eq!(TEN.powi(-127), dec64::MIN_POSITIVE);
+	// 3^40 = 12157665459056928|801
+	assert_eq!(Dec64::from_parts(3, 0).powi(40), Dec64::from_parts(12157665459056929, 3));
+}
+
+#[test]
+fn powi_compound() {
+	// 1.005^12 = 1.0616778118644995|688
+	assert_eq!(Dec64::from_parts(1005, -3).powi(12), Dec64::from_parts(10616778118644996, -16));
+	// 1.005^360 = 6.022575212263216|184
+	assert_eq!(Dec64::from_parts(1005, -3).powi(360), Dec64::from_parts(6022575212263216, -15));
+	// 1.1^-3 = 0.75131480090157776|11
+	assert_eq!(Dec64::from_parts(11, -1).powi(-3), Dec64::from_parts(7513148009015778, -16));
+}
+
+#[test]
+fn powi_special_values() {
+	assert_eq!(ZERO.powi(0), ONE);
+	assert!(NAN.powi(0).is_nan());
+	assert_eq!(ZERO.powi(3), ZERO);
+	assert_eq!(NEG_ONE.powi(i32::MAX), NEG_ONE);
+	assert_eq!(NEG_ONE.powi(i32::MIN), ONE);
+	assert_eq!(TEN.powi(-200), ZERO);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn powi_nan() {
+	assert!(ZIP.powi(-1).is_nan());
+	assert!(TEN.powi(200).is_nan());
+}
+
+#[test]
+fn checked_powi() {
+	assert_eq!(TWO.checked_powi(10), Some(Dec64::from_parts(1024, 0)));
+	assert_eq!(TEN.checked_powi(-200), Some(ZERO));
+	assert_eq!(TEN.checked_powi(200), None);
+	assert_eq!(ZERO.checked_powi(-1), None);
+	assert_eq!(NAN.checked_powi(1), None);
+}
+
+#[test]
+fn checked_powi_leaves_context_alone() {
+	Context::set_current(Context::default());
+	assert_eq!(TEN.checked_powi(200), None);
+	assert!(Context::current().flags().is_empty());
+}
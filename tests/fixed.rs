@@ -0,0 +1,119 @@
+extern crate dec64;
+
+use std::convert::TryFrom;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::Fixed;
+use dec64::Inexact;
+use dec64::NAN;
+use dec64::OutOfRange;
+use dec64::RoundingMode;
+
+type Cents = Fixed<-2>;
+
+fn cents(coefficient: i64) -> Cents {
+	Cents::from_coefficient(coefficient).unwrap()
+}
+
+#[test]
+fn fixed_new() {
+	let value = Cents::new(Dec64::from_parts(12345, -3), RoundingMode::HalfEven).unwrap();
+
+	assert_eq!(value, cents(1234));
+	assert_eq!(value.to_dec64().exponent(), -2);
+	assert_eq!(Cents::new(Dec64::from_parts(12, 0), RoundingMode::HalfEven).unwrap().coefficient(), 1200);
+	assert_eq!(Cents::new(NAN, RoundingMode::HalfEven), Err(OutOfRange));
+	assert_eq!(Cents::new(dec64::MAX, RoundingMode::HalfEven), Err(OutOfRange));
+}
+
+#[test]
+fn fixed_conversions() {
+	let dec: Dec64 = cents(1234).into();
+
+	assert_eq!(dec.coefficient(), 1234);
+	assert_eq!(dec.exponent(), -2);
+	assert_eq!(Cents::try_from(Dec64::from_parts(5, -1)), Ok(cents(50)));
+	assert_eq!(Cents::try_from(Dec64::from_parts(5, -3)), Err(Inexact));
+	assert_eq!(Cents::try_from(NAN), Err(Inexact));
+	assert_eq!(Cents::default(), cents(0));
+	assert_eq!(Cents::default().to_dec64().exponent(), -2);
+}
+
+#[test]
+fn fixed_add_sub() {
+	assert_eq!(cents(1050) + cents(275), cents(1325));
+	assert_eq!(cents(1050) - cents(2000), cents(-950));
+	assert_eq!(-cents(25), cents(-25));
+	assert_eq!(cents(dec64::MAX_COEFFICIENT).checked_add(cents(1)), None);
+	assert_eq!(cents(dec64::MIN_COEFFICIENT).checked_neg(), None);
+}
+
+#[test]
+fn fixed_mul() {
+	// 12.35 * 1.05 = 12.9675
+	assert_eq!(cents(1235) * cents(105), cents(1297));
+	assert_eq!(cents(1235).checked_mul(cents(105), RoundingMode::Ceiling), Some(cents(1297)));
+	// 0.05 * 0.5 = 0.025
+	assert_eq!(cents(5).checked_mul(cents(50), RoundingMode::HalfAwayFromZero), Some(cents(3)));
+	assert_eq!(cents(5).checked_mul(cents(50), RoundingMode::HalfEven), Some(cents(2)));
+	assert_eq!(cents(dec64::MAX_COEFFICIENT).checked_mul(cents(200), RoundingMode::HalfEven), None);
+}
+
+#[test]
+fn fixed_div() {
+	// 10.00 / 3.00 = 3.333...
+	assert_eq!(cents(1000) / cents(300), cents(333));
+	// -2.00 / 3.00 = -0.666...
+	assert_eq!(cents(-200).checked_div(cents(300), RoundingMode::HalfEven), Some(cents(-67)));
+	assert_eq!(cents(200).checked_div(cents(-300), RoundingMode::TowardZero), Some(cents(-66)));
+	assert_eq!(cents(100).checked_div(cents(0), RoundingMode::HalfEven), None);
+}
+
+#[test]
+fn fixed_mul_div_context() {
+	// 0.05 * 0.5 = 0.025 and 10.00 / 3.00 = 3.333...
+	Context::set_current(Context::new(RoundingMode::Ceiling));
+	assert_eq!(cents(5) * cents(50), cents(3));
+	assert_eq!(cents(1000) / cents(300), cents(334));
+	Context::set_current(Context::new(RoundingMode::Floor));
+	assert_eq!(cents(5) * cents(50), cents(2));
+	assert_eq!(cents(-1000) / cents(300), cents(-334));
+	Context::set_current(Context::default());
+}
+
+#[test]
+fn fixed_positive_scale() {
+	type Thousands = Fixed<3>;
+
+	let value = Thousands::new(Dec64::from_parts(12500, 0), RoundingMode::HalfEven).unwrap();
+
+	assert_eq!(value.coefficient(), 12);
+	assert_eq!(value * Thousands::from_coefficient(2).unwrap(), Thousands::from_coefficient(24000).unwrap());
+	assert_eq!(value / Thousands::from_coefficient(5).unwrap(), Thousands::from_coefficient(0).unwrap());
+}
+
+#[test]
+fn fixed_ordering() {
+	assert!(cents(-1) < cents(0));
+	assert!(cents(150) > cents(149));
+	assert_eq!(cents(7).max(cents(3)), cents(7));
+}
+
+#[test]
+#[should_panic]
+fn fixed_div_by_zero() {
+	let _ = cents(100) / cents(0);
+}
+
+#[test]
+#[should_panic]
+fn fixed_add_overflow() {
+	let _ = cents(dec64::MAX_COEFFICIENT) + cents(1);
+}
+
+#[test]
+#[should_panic]
+fn fixed_neg_overflow() {
+	let _ = -cents(dec64::MIN_COEFFICIENT);
+}
@@ -0,0 +1,79 @@
+extern crate dec64;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+#[test]
+fn mul_add_single_rounding() {
+	let value = Dec64::from_parts(10000000000000001, -16);
+
+	// 1.0000000000000001² - 1 = 2.0000000000000001e-16
+	assert_eq!(value.mul_add(value, NEG_ONE), Dec64::from_parts(20000000000000001, -32));
+	// Rounding the square first would leave 2e-16.
+	assert_eq!(Dec64::from_parts(10000000000000002, -16) - ONE, Dec64::from_parts(2, -16));
+}
+
+#[test]
+fn mul_add_exact() {
+	let tenth = Dec64::from_parts(1, -1);
+
+	assert_eq!(tenth.mul_add(tenth, Dec64::from_parts(-1, -2)), ZERO);
+	assert_eq!(TWO.mul_add(Dec64::from_parts(3, 0), ONE), Dec64::from_parts(7, 0));
+	assert_eq!(ZERO.mul_add(TWO, ONE), ONE);
+	assert_eq!(TWO.mul_add(TWO, ZERO), Dec64::from_parts(4, 0));
+}
+
+#[test]
+fn mul_add_large() {
+	// MAX_COEFFICIENT² + 1 = 1298074214633706|835075030044377090
+	let expect = Dec64::from_parts(12980742146337068, 17);
+
+	assert_eq!(Dec64::from_parts(dec64::MAX_COEFFICIENT, 0).mul_add(Dec64::from_parts(dec64::MAX_COEFFICIENT, 0), ONE), expect);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn mul_add_overflow() {
+	assert!(dec64::MAX.mul_add(TWO, ZERO).is_nan());
+}
+
+#[test]
+fn mul_div_fee() {
+	// 123456789.12 * 25 / 10000 = 308641.9728
+	let fee = Dec64::from_parts(12345678912, -2).mul_div(Dec64::from_parts(25, 0), Dec64::from_parts(10000, 0));
+
+	assert_eq!(fee.coefficient(), 3086419728);
+	assert_eq!(fee.exponent(), -4);
+}
+
+#[test]
+fn mul_div_single_rounding() {
+	// 12345678901234.56 * 1.0834567890123 / 0.97 = 13789700639699.480|79
+	let notional = Dec64::from_parts(1234567890123456, -2);
+	let rate = Dec64::from_parts(10834567890123, -13);
+	let expect = Dec64::from_parts(13789700639699481, -3);
+
+	assert_eq!(notional.mul_div(rate, Dec64::from_parts(97, -2)), expect);
+	// 2 / 3 = 0.66666666666666666|67
+	assert_eq!(TWO.mul_div(ONE, Dec64::from_parts(3, 0)), Dec64::from_parts(6666666666666667, -16));
+}
+
+#[test]
+fn mul_div_nan() {
+	assert!(NAN.mul_div(ONE, ONE).is_nan());
+	assert_eq!(ONE.mul_div(NAN_NAN, ONE), NAN_NAN);
+	assert!(ONE.mul_add(ONE, NAN).is_nan());
+	assert_eq!(ZERO.mul_div(ONE, TWO), ZERO);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn mul_div_by_zero() {
+	assert!(ONE.mul_div(ONE, ZERO).is_nan());
+	assert!(ZERO.mul_div(ONE, ZERO).is_nan());
+}
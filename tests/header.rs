@@ -0,0 +1,124 @@
+//! Checks that `include/dec64.h` declares what `src/ffi.rs` exports. The
+//! header is generated from the list of functions there: run with
+//! `DEC64_BLESS=1` to write it anew after changing them.
+
+extern crate dec64;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const PROLOGUE: &str = "\
+/* dec64.h
+
+Generated from src/ffi.rs by tests/header.rs, do not edit.
+
+Link with the dec64 crate built with the `c-api` feature, as a cdylib or a
+staticlib:
+
+    cargo rustc --release --features c-api --crate-type cdylib
+    cargo rustc --release --features c-api --crate-type staticlib
+
+The functions have the names and signatures of the reference dec64.h, and
+behave like it.
+
+To change this file, change the list in src/ffi.rs and run the tests with
+DEC64_BLESS=1 set, which writes it anew.
+*/
+
+#ifndef DEC64_H
+#define DEC64_H
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+
+typedef long long int64;
+typedef int64 dec64;
+
+#define DEC64_NAN   0x80LL
+#define DEC64_ZERO  0x00LL
+#define DEC64_ONE   0x100LL
+#define DEC64_TRUE  0x380LL
+#define DEC64_FALSE 0x280LL
+
+";
+
+const EPILOGUE: &str = "
+#ifdef __cplusplus
+}
+#endif
+
+#endif
+";
+
+fn c_type(rust: &str) -> &str {
+	match rust.trim() {
+		"Dec64" => "dec64",
+		"i64" => "int64",
+		other => panic!("no C type for {}", other),
+	}
+}
+
+/// Turns `fn name(a: Dec64, b: i64) -> Dec64;` into a C declaration.
+fn declaration(line: &str) -> Option<String> {
+	let line = line.trim().strip_prefix("fn ")?.strip_suffix(';')?;
+	let (name, rest) = line.split_once('(')?;
+	let (parameters, result) = rest.split_once(") ->")?;
+	let parameters: Vec<String> = parameters.split(',').map(|parameter| {
+		let (name, rust) = parameter.split_once(':').unwrap();
+		format!("{} {}", c_type(rust), name.trim())
+	}).collect();
+
+	Some(format!("{} {}({});", c_type(result), name, parameters.join(", ")))
+}
+
+fn generate(source: &str) -> String {
+	let declarations: Vec<String> = source.lines().filter_map(declaration).collect();
+	assert!(!declarations.is_empty(), "no functions found to declare");
+
+	format!("{}{}\n{}", PROLOGUE, declarations.join("\n"), EPILOGUE)
+}
+
+#[test]
+fn header_up_to_date() {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let header = root.join("include").join("dec64.h");
+	let expected = generate(&fs::read_to_string(root.join("src").join("ffi.rs")).unwrap());
+
+	if env::var_os("DEC64_BLESS").is_some() {
+		fs::create_dir_all(header.parent().unwrap()).unwrap();
+		fs::write(&header, &expected).unwrap();
+	}
+
+	let actual = fs::read_to_string(&header).unwrap_or_default();
+	assert!(actual == expected, "{} is out of date, run the tests with DEC64_BLESS=1", header.display());
+}
+
+#[cfg(feature = "c-api")]
+mod c_api {
+	use dec64::Dec64;
+	use dec64::crockford::DEC64_TRUE;
+
+	extern "C" {
+		fn dec64_new(coefficient: i64, exponent: i64) -> i64;
+		fn dec64_add(augend: i64, addend: i64) -> i64;
+		fn dec64_is_less(comparahend: Dec64, comparator: Dec64) -> Dec64;
+		fn dec64_coefficient(number: i64) -> i64;
+	}
+
+	#[test]
+	fn c_api_calls() {
+		unsafe {
+			// 1.5 + 25e-1 is 40e-1.
+			let sum = dec64_add(dec64_new(15, -1), 0x19ff);
+			assert_eq!(sum, 0x28ff);
+			assert_eq!(dec64_coefficient(sum), 40);
+			assert_eq!(dec64_add(0x100, 0x80), 0x80);
+
+			let less = dec64_is_less(Dec64::from_parts(1, 0), Dec64::from_parts(2, 0));
+			assert_eq!(less.coefficient(), DEC64_TRUE.coefficient());
+			assert!(less.is_nan());
+		}
+	}
+}
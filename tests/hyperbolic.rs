@@ -0,0 +1,122 @@
+extern crate dec64;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::HALF;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+#[test]
+fn hyperbolic_zero() {
+	assert_eq!(ZERO.sinh(), ZERO);
+	assert_eq!(ZIP.sinh(), ZERO);
+	assert_eq!(ZERO.cosh(), ONE);
+	assert_eq!(ZERO.tanh(), ZERO);
+	assert_eq!(ZERO.asinh(), ZERO);
+	assert_eq!(ONE.acosh(), ZERO);
+	assert_eq!(ZERO.atanh(), ZERO);
+}
+
+#[test]
+fn hyperbolic_one() {
+	// sinh(1) = 1.1752011936438014|57
+	assert_eq!(ONE.sinh(), Dec64::from_parts(11752011936438015, -16));
+	// cosh(1) = 1.5430806348152437|78
+	assert_eq!(ONE.cosh(), Dec64::from_parts(15430806348152438, -16));
+	// tanh(1) = 0.76159415595576488|81
+	assert_eq!(ONE.tanh(), Dec64::from_parts(7615941559557649, -16));
+	// sinh(-1) = -sinh(1)
+	assert_eq!(NEG_ONE.sinh(), Dec64::from_parts(-11752011936438015, -16));
+	assert_eq!(NEG_ONE.cosh(), ONE.cosh());
+}
+
+#[test]
+fn hyperbolic_tiny() {
+	let tiny = Dec64::from_parts(1, -20);
+
+	assert_eq!(tiny.sinh(), tiny);
+	assert_eq!(tiny.cosh(), ONE);
+	assert_eq!(tiny.tanh(), tiny);
+	assert_eq!(tiny.asinh(), tiny);
+	assert_eq!(tiny.atanh(), tiny);
+}
+
+#[test]
+fn hyperbolic_small() {
+	let small = Dec64::from_parts(1, -5);
+
+	// sinh(0.00001) = 1.0000000000166666|67e-5
+	assert_eq!(small.sinh(), Dec64::from_parts(10000000000166667, -21));
+	// tanh(0.00001) = 9.999999999666666|668e-6
+	assert_eq!(small.tanh(), Dec64::from_parts(9999999999666667, -21));
+	// asinh(0.00001) = 9.999999999833333|333e-6
+	assert_eq!(small.asinh(), Dec64::from_parts(9999999999833333, -21));
+	// atanh(0.00001) = 1.0000000000333333|33e-5
+	assert_eq!(small.atanh(), Dec64::from_parts(10000000000333333, -21));
+}
+
+#[test]
+fn hyperbolic_large() {
+	let twenty = Dec64::from_parts(-20, 0);
+
+	// sinh(-20) = -242582597.70489513|8
+	assert_eq!(twenty.sinh(), Dec64::from_parts(-24258259770489514, -8));
+	// tanh(-20) = -0.99999999999999999|15
+	assert_eq!(twenty.tanh(), NEG_ONE);
+	assert_eq!(dec64::MAX.tanh(), ONE);
+	assert_eq!(dec64::MIN.tanh(), NEG_ONE);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn hyperbolic_overflow() {
+	assert!(Dec64::from_parts(400, 0).sinh().is_nan());
+	assert!(Dec64::from_parts(-400, 0).cosh().is_nan());
+}
+
+#[test]
+fn asinh_values() {
+	// asinh(1) = 0.88137358701954302|52
+	assert_eq!(ONE.asinh(), Dec64::from_parts(8813735870195430, -16));
+	assert_eq!(NEG_ONE.asinh(), Dec64::from_parts(-8813735870195430, -16));
+	// asinh(1e100) = 230.95165647996451|2
+	assert_eq!(Dec64::from_parts(1, 100).asinh(), Dec64::from_parts(23095165647996451, -14));
+}
+
+#[test]
+fn acosh_values() {
+	// acosh(2) = 1.3169578969248167|09
+	assert_eq!(TWO.acosh(), Dec64::from_parts(13169578969248167, -16));
+	// acosh(1.00001) = 4.472132228228002|123e-3
+	assert_eq!(Dec64::from_parts(100001, -5).acosh(), Dec64::from_parts(4472132228228002, -18));
+}
+
+#[test]
+fn atanh_values() {
+	// atanh(0.5) = 0.54930614433405484|57
+	assert_eq!(HALF.atanh(), Dec64::from_parts(5493061443340548, -16));
+	// atanh(0.99) = 2.6466524123622461|98
+	assert_eq!(Dec64::from_parts(99, -2).atanh(), Dec64::from_parts(26466524123622462, -16));
+}
+
+#[test]
+fn hyperbolic_nan() {
+	assert!(NAN.sinh().is_nan());
+	assert_eq!(NAN_NAN.cosh(), NAN_NAN);
+	assert!(NAN.tanh().is_nan());
+	assert!(NAN.asinh().is_nan());
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn hyperbolic_invalid() {
+	assert!(HALF.acosh().is_nan());
+	assert!(NEG_ONE.acosh().is_nan());
+	assert!(ONE.atanh().is_nan());
+	assert!(NEG_ONE.atanh().is_nan());
+	assert!(TWO.atanh().is_nan());
+}
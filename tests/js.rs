@@ -0,0 +1,112 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::NAN;
+use dec64::js;
+
+use common::d;
+
+#[test]
+fn js_to_string() {
+	assert_eq!(js::to_string(d(0, 0)), "0");
+	assert_eq!(js::to_string(d(0, 5)), "0");
+	assert_eq!(js::to_string(d(123, 0)), "123");
+	assert_eq!(js::to_string(d(1230, -1)), "123");
+	assert_eq!(js::to_string(d(-15, -1)), "-1.5");
+	assert_eq!(js::to_string(d(123456, -3)), "123.456");
+	assert_eq!(js::to_string(d(1, 20)), "100000000000000000000");
+	assert_eq!(js::to_string(d(1, 21)), "1e+21");
+	assert_eq!(js::to_string(d(1234, 50)), "1.234e+53");
+	assert_eq!(js::to_string(d(1, -6)), "0.000001");
+	assert_eq!(js::to_string(d(1, -7)), "1e-7");
+	assert_eq!(js::to_string(d(-5, -127)), "-5e-127");
+	assert_eq!(js::to_string(NAN), "NaN");
+}
+
+#[test]
+fn js_to_fixed() {
+	assert_eq!(js::to_fixed(d(12345, -3), 2).unwrap(), "12.35");
+	assert_eq!(js::to_fixed(d(1005, -3), 2).unwrap(), "1.01");
+	assert_eq!(js::to_fixed(d(999, -3), 2).unwrap(), "1.00");
+	assert_eq!(js::to_fixed(d(5, -1), 0).unwrap(), "1");
+	assert_eq!(js::to_fixed(d(-25, -1), 0).unwrap(), "-3");
+	assert_eq!(js::to_fixed(d(-1, -4), 2).unwrap(), "-0.00");
+	assert_eq!(js::to_fixed(d(0, 0), 3).unwrap(), "0.000");
+	assert_eq!(js::to_fixed(d(12, 3), 1).unwrap(), "12000.0");
+	assert_eq!(js::to_fixed(d(1, 21), 2).unwrap(), "1e+21");
+	assert_eq!(js::to_fixed(NAN, 2).unwrap(), "NaN");
+	assert_eq!(js::to_fixed(d(1, 0), 101), None);
+}
+
+#[test]
+fn js_to_exponential() {
+	assert_eq!(js::to_exponential(d(12345, -3), Some(2)).unwrap(), "1.23e+1");
+	assert_eq!(js::to_exponential(d(123456, 0), None).unwrap(), "1.23456e+5");
+	assert_eq!(js::to_exponential(d(1, -7), None).unwrap(), "1e-7");
+	assert_eq!(js::to_exponential(d(-999, 0), Some(1)).unwrap(), "-1.0e+3");
+	assert_eq!(js::to_exponential(d(0, 0), None).unwrap(), "0e+0");
+	assert_eq!(js::to_exponential(d(0, 0), Some(2)).unwrap(), "0.00e+0");
+	assert_eq!(js::to_exponential(d(1, 0), Some(101)), None);
+}
+
+#[test]
+fn js_to_precision() {
+	assert_eq!(js::to_precision(d(12345, -3), Some(3)).unwrap(), "12.3");
+	assert_eq!(js::to_precision(d(123, 0), Some(3)).unwrap(), "123");
+	assert_eq!(js::to_precision(d(123, 0), Some(5)).unwrap(), "123.00");
+	assert_eq!(js::to_precision(d(123456, 0), Some(2)).unwrap(), "1.2e+5");
+	assert_eq!(js::to_precision(d(99, -2), Some(1)).unwrap(), "1");
+	assert_eq!(js::to_precision(d(1, -6), Some(2)).unwrap(), "0.0000010");
+	assert_eq!(js::to_precision(d(1, -7), Some(2)).unwrap(), "1.0e-7");
+	assert_eq!(js::to_precision(d(0, 0), Some(3)).unwrap(), "0.00");
+	assert_eq!(js::to_precision(d(-15, -1), None).unwrap(), "-1.5");
+	assert_eq!(js::to_precision(d(1, 0), Some(0)), None);
+	assert_eq!(js::to_precision(d(1, 0), Some(101)), None);
+}
+
+#[test]
+fn js_to_string_radix() {
+	assert_eq!(js::to_string_radix(d(255, 0), 16).unwrap(), "ff");
+	assert_eq!(js::to_string_radix(d(-255, 0), 2).unwrap(), "-11111111");
+	assert_eq!(js::to_string_radix(d(5, -1), 2).unwrap(), "0.1");
+	assert_eq!(js::to_string_radix(d(-2625, -3), 16).unwrap(), "-2.a");
+	assert_eq!(js::to_string_radix(d(1, 30), 16).unwrap(), "c9f2c9cd04674edea40000000");
+	assert_eq!(js::to_string_radix(d(123, 0), 10).unwrap(), "123");
+	assert_eq!(js::to_string_radix(d(0, 0), 2).unwrap(), "0");
+
+	// A fraction that does not end is cut off and rounded.
+	assert_eq!(js::to_string_radix(d(1, -1), 16).unwrap(), "0.19999999999999a");
+
+	assert_eq!(js::to_string_radix(d(1, 0), 1), None);
+	assert_eq!(js::to_string_radix(d(1, 0), 37), None);
+}
+
+#[test]
+fn js_to_int32() {
+	assert_eq!(js::to_int32(d(-1, 0)), -1);
+	assert_eq!(js::to_uint32(d(-1, 0)), u32::MAX);
+	assert_eq!(js::to_int32(d(4294967301, 0)), 5);
+	assert_eq!(js::to_int32(d(2147483648, 0)), i32::MIN);
+	assert_eq!(js::to_uint32(d(2147483648, 0)), 2147483648);
+	assert_eq!(js::to_int32(d(21474836475, -1)), i32::MAX);
+	assert_eq!(js::to_int32(d(-15, -1)), -1);
+	assert_eq!(js::to_int32(d(3, -1)), 0);
+	assert_eq!(js::to_int32(d(1, 20)), 1661992960);
+	assert_eq!(js::to_int32(d(1, -127)), 0);
+	assert_eq!(js::to_int32(NAN), 0);
+}
+
+#[test]
+fn js_bitwise() {
+	assert_eq!(js::bit_and(d(12, 0), d(10, 0)), d(8, 0));
+	assert_eq!(js::bit_or(d(12, 0), d(10, 0)), d(14, 0));
+	assert_eq!(js::bit_xor(d(12, 0), d(10, 0)), d(6, 0));
+	assert_eq!(js::bit_not(d(5, 0)), d(-6, 0));
+	assert_eq!(js::bit_or(d(37, -1), NAN), d(3, 0));
+	assert_eq!(js::shl(d(1, 0), d(31, 0)), d(i32::MIN as i64, 0));
+	assert_eq!(js::shl(d(1, 0), d(33, 0)), d(2, 0));
+	assert_eq!(js::shr(d(-16, 0), d(2, 0)), d(-4, 0));
+	assert_eq!(js::ushr(d(-16, 0), d(28, 0)), d(15, 0));
+	assert_eq!(js::ushr(d(-1, 0), d(0, 0)), d(u32::MAX as i64, 0));
+}
@@ -0,0 +1,68 @@
+extern crate dec64;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+const MISSING: i64 = 1;
+const STALE: i64 = 2;
+const ERROR: i64 = -3;
+
+#[test]
+fn nan_with_payload() {
+	assert_eq!(Dec64::nan_with_payload(0), NAN);
+	assert_eq!(Dec64::nan_with_payload(128), NAN_NAN);
+	assert!(Dec64::nan_with_payload(ERROR).is_nan());
+	assert_eq!(Dec64::nan_with_payload(dec64::MAX_COEFFICIENT).nan_payload(), Some(dec64::MAX_COEFFICIENT));
+	assert_eq!(Dec64::nan_with_payload(dec64::MIN_COEFFICIENT).nan_payload(), Some(dec64::MIN_COEFFICIENT));
+	assert_eq!(Dec64::nan_with_payload(dec64::MAX_COEFFICIENT + 1), NAN);
+}
+
+#[test]
+fn nan_payload() {
+	assert_eq!(NAN.nan_payload(), Some(0));
+	assert_eq!(NAN_NAN.nan_payload(), Some(128));
+	assert_eq!(Dec64::nan_with_payload(STALE).nan_payload(), Some(STALE));
+	assert_eq!(ZERO.nan_payload(), None);
+	assert_eq!(ONE.nan_payload(), None);
+
+	// Payloads tell NaNs apart.
+	assert_ne!(Dec64::nan_with_payload(MISSING), Dec64::nan_with_payload(STALE));
+}
+
+#[test]
+fn nan_propagation() {
+	let missing = Dec64::nan_with_payload(MISSING);
+	let stale = Dec64::nan_with_payload(STALE);
+
+	assert_eq!((missing + ONE).nan_payload(), Some(MISSING));
+	assert_eq!((ONE + missing).nan_payload(), Some(MISSING));
+	assert_eq!((ONE - stale).nan_payload(), Some(STALE));
+	assert_eq!((-stale).nan_payload(), Some(STALE));
+	assert_eq!(Dec64::nan_with_payload(ERROR).abs().nan_payload(), Some(ERROR));
+
+	// Of two NaNs, the first one is passed on.
+	assert_eq!((missing + stale).nan_payload(), Some(MISSING));
+	assert_eq!((stale + missing).nan_payload(), Some(STALE));
+	assert_eq!(stale.mul_add(ONE, missing).nan_payload(), Some(STALE));
+	assert_eq!(ONE.mul_div(missing, stale).nan_payload(), Some(MISSING));
+	assert_eq!(ONE.maximum(stale).nan_payload(), Some(STALE));
+
+	assert_eq!(missing.sqrt().nan_payload(), Some(MISSING));
+	assert_eq!(stale.ln().nan_payload(), Some(STALE));
+	assert_eq!(TWO.pow(missing).nan_payload(), Some(MISSING));
+	assert_eq!(missing.sin_cos(), (missing, missing));
+}
+
+#[cfg(not(any(feature = "nan-reason", feature = "strict")))]
+#[test]
+fn nan_produced() {
+	assert_eq!(dec64::MAX + dec64::MAX, NAN);
+	assert_eq!(ONE.mul_div(ONE, ZERO), NAN);
+	assert_eq!(Dec64::from_parts(-1, 0).sqrt(), NAN);
+	assert_eq!(ZERO.ln(), NAN);
+	assert_eq!(Dec64::nan_with_payload(STALE).normalize(), NAN);
+}
@@ -0,0 +1,77 @@
+// Nearly every test here has operations produce NaNs, which panic instead
+// with the `strict` feature.
+#![cfg(not(feature = "strict"))]
+
+extern crate dec64;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::NanReason;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+#[test]
+fn nan_reason_decode() {
+	assert_eq!(ONE.nan_reason(), None);
+	assert_eq!(NAN_NAN.nan_reason(), None);
+	assert_eq!(Dec64::nan_with_payload(7).nan_reason(), None);
+	assert_eq!(Dec64::nan_with_payload(dec64::MIN_COEFFICIENT).nan_reason(), None);
+	assert_eq!(Dec64::nan_with_payload(dec64::MIN_COEFFICIENT + 1).nan_reason(), Some(NanReason::Overflow));
+	assert_eq!(Dec64::nan_with_payload(dec64::MIN_COEFFICIENT + 4).nan_reason(), Some(NanReason::NanOperand));
+}
+
+#[cfg(not(feature = "nan-reason"))]
+#[test]
+fn nan_reason_disabled() {
+	assert_eq!(dec64::MAX + dec64::MAX, NAN);
+	assert_eq!(NEG_ONE.sqrt().nan_reason(), None);
+	assert_eq!(TWO.asin().nan_reason(), None);
+	assert_eq!(Context::default().div(ZERO, ZERO), NAN);
+	assert_eq!((NAN + ONE).nan_reason(), None);
+}
+
+#[cfg(feature = "nan-reason")]
+#[test]
+fn nan_reason_overflow() {
+	assert_eq!((dec64::MAX + dec64::MAX).nan_reason(), Some(NanReason::Overflow));
+	assert_eq!(Dec64::pack(1, 200).nan_reason(), Some(NanReason::Overflow));
+	assert_eq!(dec64::MAX.exp().nan_reason(), Some(NanReason::Overflow));
+	assert_eq!(dec64::MAX.next_up().nan_reason(), Some(NanReason::Overflow));
+}
+
+#[cfg(feature = "nan-reason")]
+#[test]
+fn nan_reason_invalid() {
+	assert_eq!(NEG_ONE.sqrt().nan_reason(), Some(NanReason::Invalid));
+	assert_eq!(ZERO.ln().nan_reason(), Some(NanReason::Invalid));
+	assert_eq!(TWO.asin().nan_reason(), Some(NanReason::Invalid));
+	assert_eq!(NEG_ONE.pow(Dec64::from_parts(5, -1)).nan_reason(), Some(NanReason::Invalid));
+	assert_eq!(Context::default().div(ZERO, ZERO).nan_reason(), Some(NanReason::Invalid));
+	assert_eq!(ZERO.mul_div(ONE, ZERO).nan_reason(), Some(NanReason::Invalid));
+}
+
+#[cfg(feature = "nan-reason")]
+#[test]
+fn nan_reason_division_by_zero() {
+	assert_eq!(Context::default().div(ONE, ZERO).nan_reason(), Some(NanReason::DivisionByZero));
+	assert_eq!(ONE.mul_div(ONE, ZERO).nan_reason(), Some(NanReason::DivisionByZero));
+	assert_eq!(ZERO.powi(-1).nan_reason(), Some(NanReason::DivisionByZero));
+}
+
+#[cfg(feature = "nan-reason")]
+#[test]
+fn nan_reason_operand() {
+	assert_eq!((NAN + ONE).nan_reason(), Some(NanReason::NanOperand));
+	assert_eq!((-NAN).nan_reason(), Some(NanReason::NanOperand));
+	assert_eq!(ONE.hypot(NAN).nan_reason(), Some(NanReason::NanOperand));
+
+	// A reason, like any payload, survives the rest of the calculation.
+	let overflow = dec64::MAX + dec64::MAX;
+	assert_eq!(overflow.mul_add(ONE, ONE).nan_reason(), Some(NanReason::Overflow));
+	assert_eq!((ONE - overflow.sqrt()).nan_reason(), Some(NanReason::Overflow));
+	assert_eq!(NAN_NAN + ONE, NAN_NAN);
+}
@@ -0,0 +1,71 @@
+extern crate dec64;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::normal::ONE;
+use dec64::nanbox;
+use dec64::nanbox::Value;
+
+fn roundtrip(value: Value) -> Value {
+	nanbox::decode(nanbox::encode(value).unwrap())
+}
+
+#[test]
+fn nanbox_roundtrip() {
+	let object = 42u64;
+	let pointer = &object as *const u64 as *const ();
+
+	assert_eq!(roundtrip(Value::Pointer(pointer)), Value::Pointer(pointer));
+	assert_eq!(roundtrip(Value::Handle(0)), Value::Handle(0));
+	assert_eq!(roundtrip(Value::Handle(u32::MAX)), Value::Handle(u32::MAX));
+	assert_eq!(roundtrip(Value::Bool(true)), Value::Bool(true));
+	assert_eq!(roundtrip(Value::Bool(false)), Value::Bool(false));
+	assert_eq!(roundtrip(Value::Null), Value::Null);
+	assert_eq!(roundtrip(Value::Number(ONE)), Value::Number(ONE));
+	assert_eq!(roundtrip(Value::Number(dec64::MIN)), Value::Number(dec64::MIN));
+}
+
+#[test]
+fn nanbox_words() {
+	let null = nanbox::encode(Value::Null).unwrap();
+	let yes = nanbox::encode(Value::Bool(true)).unwrap();
+	let handle = nanbox::encode(Value::Handle(7)).unwrap();
+
+	// Boxed values are NaNs, distinct from each other and from numbers.
+	assert!(null.is_nan() && yes.is_nan() && handle.is_nan());
+	assert!(nanbox::is_boxed(null));
+	assert_ne!(null, yes);
+	assert_ne!(null, NAN);
+	assert!(!nanbox::is_boxed(ONE));
+	assert!(!nanbox::is_boxed(NAN));
+}
+
+#[test]
+fn nanbox_nan_numbers() {
+	// Ordinary NaNs stay numbers.
+	assert_eq!(roundtrip(Value::Number(NAN)), Value::Number(NAN));
+	assert_eq!(roundtrip(Value::Number(NAN_NAN)), Value::Number(NAN_NAN));
+	let payload = Dec64::nan_with_payload(-0x70_0000_0000_0000);
+	assert_eq!(roundtrip(Value::Number(payload)), Value::Number(payload));
+
+	// Unknown tags and out of range data decode as numbers, and stay plain
+	// numbers when they go back through encode.
+	let unknown = Dec64::nan_with_payload(0x7F_0000_0000_0000);
+	assert_eq!(nanbox::decode(unknown), Value::Number(unknown));
+	assert_eq!(nanbox::encode(Value::Number(unknown)), Some(unknown));
+	let boolean = Dec64::nan_with_payload(0x72_0000_0000_0002);
+	assert_eq!(nanbox::decode(boolean), Value::Number(boolean));
+
+	// A NaN that looks like a boxed value can not be a number.
+	let null = nanbox::encode(Value::Null).unwrap();
+	assert_eq!(nanbox::encode(Value::Number(null)), None);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn nanbox_wide_pointer() {
+	let widest = 0xFFFF_FFFF_FFFF_usize as *const ();
+	assert_eq!(roundtrip(Value::Pointer(widest)), Value::Pointer(widest));
+	assert_eq!(nanbox::encode(Value::Pointer(0x1_0000_0000_0000_usize as *const ())), None);
+}
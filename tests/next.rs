@@ -0,0 +1,90 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::Dec64;
+use dec64::EPSILON;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+
+use common::parts;
+
+#[test]
+fn next_one() {
+	assert_eq!(parts(ONE.next_up()), (10000000000000001, -16));
+	assert_eq!(parts(ONE.next_down()), (9999999999999999, -16));
+	assert_eq!(parts(NEG_ONE.next_up()), (-9999999999999999, -16));
+	assert_eq!(parts(NEG_ONE.next_down()), (-10000000000000001, -16));
+	assert_eq!(parts(Dec64::from_parts(10000000000000001, -16).next_down()), (1, 0));
+}
+
+#[test]
+fn next_zero() {
+	assert_eq!(ZERO.next_up(), dec64::MIN_POSITIVE);
+	assert_eq!(parts(ZIP.next_down()), (-1, -127));
+	assert_eq!(parts(dec64::MIN_POSITIVE.next_down()), parts(ZERO));
+	assert_eq!(parts(Dec64::from_parts(-1, -127).next_up()), parts(ZERO));
+}
+
+#[test]
+fn next_across_exponents() {
+	let max_coefficient = Dec64::from_parts(dec64::MAX_COEFFICIENT, 0);
+	let above = Dec64::from_parts(3602879701896397, 1);
+
+	assert_eq!(parts(max_coefficient.next_up()), (3602879701896397, 1));
+	assert_eq!(parts(above.next_down()), (dec64::MAX_COEFFICIENT, 0));
+	assert_eq!(parts((-above).next_up()), (dec64::MIN_COEFFICIENT, 0));
+	assert_eq!(parts(Dec64::from_parts(dec64::MIN_COEFFICIENT, 0).next_down()), (-3602879701896397, 1));
+	assert_eq!(parts(Dec64::from_parts(-3602879701896397, 0).next_up()), (-36028797018963968, -1));
+}
+
+#[test]
+fn next_extremes() {
+	assert_eq!(parts(dec64::MAX.next_down()), (dec64::MAX_COEFFICIENT - 1, 127));
+	assert_eq!(parts(dec64::MIN.next_up()), (dec64::MIN_COEFFICIENT + 1, 127));
+	assert!(NAN.next_up().is_nan());
+	assert_eq!(NAN_NAN.next_down(), NAN_NAN);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn next_overflow() {
+	assert!(dec64::MAX.next_up().is_nan());
+	assert!(dec64::MIN.next_down().is_nan());
+}
+
+#[test]
+fn next_price_ladder() {
+	let mut price = Dec64::from_parts(9999999999999998, -16);
+	let mut ladder = Vec::new();
+	for _ in 0..4 {
+		price = price.next_up();
+		ladder.push(parts(price));
+	}
+
+	assert_eq!(ladder, [(9999999999999999, -16), (1, 0), (10000000000000001, -16), (10000000000000002, -16)]);
+}
+
+#[test]
+fn ulp() {
+	assert_eq!(ONE.ulp(), EPSILON);
+	assert_eq!(NEG_ONE.ulp(), EPSILON);
+	assert_eq!(parts(Dec64::from_parts(5, -1).ulp()), (1, -16));
+	assert_eq!(parts(Dec64::from_parts(3, -1).ulp()), (1, -17));
+	assert_eq!(parts(Dec64::from_parts(1, 20).ulp()), (1, 4));
+	assert_eq!(parts(dec64::MAX.ulp()), (1, 127));
+	assert_eq!(ZERO.ulp(), dec64::MIN_POSITIVE);
+	assert_eq!(dec64::MIN_POSITIVE.ulp(), dec64::MIN_POSITIVE);
+	assert!(NAN.ulp().is_nan());
+}
+
+#[test]
+fn ulp_matches_next_up() {
+	for &value in &[ONE, Dec64::from_parts(5, -1), Dec64::from_parts(123456, 7), Dec64::from_parts(-42, -3)] {
+		assert_eq!(value.next_up() - value, value.ulp(), "@ {:?}", value);
+	}
+}
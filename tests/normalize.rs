@@ -0,0 +1,53 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+
+use common::parts;
+
+#[test]
+fn normalize_trailing_zeros() {
+	assert_eq!(parts(Dec64::from_parts(1200, -2).normalize()), (12, 0));
+	assert_eq!(parts(Dec64::from_parts(1500, -3).normalize()), (15, -1));
+	assert_eq!(parts(Dec64::from_parts(-1000, 5).normalize()), (-1, 8));
+	assert_eq!(parts(Dec64::from_parts(10000000000000000, -16).normalize()), (1, 0));
+	assert_eq!(parts(Dec64::from_parts(123, -2).normalize()), (123, -2));
+}
+
+#[test]
+fn normalize_max_exponent() {
+	assert_eq!(parts(Dec64::from_parts(100, 126).normalize()), (10, 127));
+	assert_eq!(parts(Dec64::from_parts(100, 127).normalize()), (100, 127));
+}
+
+#[test]
+fn normalize_zero_and_nan() {
+	assert_eq!(parts(ZIP.normalize()), parts(ZERO));
+	assert_eq!(parts(Dec64::from_parts(0, -5).normalize()), parts(ZERO));
+	assert_eq!(parts(NAN_NAN.normalize()), parts(NAN));
+	assert_eq!(parts(Dec64::from_parts(-7, -128).normalize()), parts(NAN));
+}
+
+#[test]
+fn is_canonical() {
+	assert!(ZERO.is_canonical());
+	assert!(NAN.is_canonical());
+	assert!(Dec64::from_parts(12, 0).is_canonical());
+	assert!(Dec64::from_parts(100, 127).is_canonical());
+	assert!(!Dec64::from_parts(1200, -2).is_canonical());
+	assert!(!ZIP.is_canonical());
+	assert!(!NAN_NAN.is_canonical());
+}
+
+#[test]
+fn normalize_keeps_value() {
+	let value = Dec64::from_parts(4500, -3);
+
+	assert_eq!(value.normalize(), value);
+	assert!(value.normalize().is_canonical());
+}
@@ -1,6 +1,7 @@
 extern crate dec64;
 
 use dec64::Dec64;
+use dec64::RoundingMode;
 
 #[test]
 fn pack_zero() {
@@ -73,26 +74,24 @@ fn pack_max() {
 	assert_eq!(result, expect);
 }
 
+#[cfg(not(feature = "strict"))]
 #[test]
 fn pack_min_minus_one() {
 	let coefficient = dec64::MIN_COEFFICIENT-1;
 	let exponent    = dec64::MAX_EXP;
-	let expect = dec64::NAN;
 	let result = Dec64::pack(coefficient, exponent);
 
 	assert!(result.is_nan());
-	assert_eq!(result, expect);
 }
 
+#[cfg(not(feature = "strict"))]
 #[test]
 fn pack_max_plus_one() {
 	let coefficient = dec64::MAX_COEFFICIENT+1;
 	let exponent    = dec64::MAX_EXP;
-	let expect = dec64::NAN;
 	let result = Dec64::pack(coefficient, exponent);
 
 	assert!(result.is_nan());
-	assert_eq!(result, expect);
 }
 
 #[test]
@@ -109,15 +108,14 @@ fn pack_reduce_exp() {
 	assert_eq!(result, expect);
 }
 
+#[cfg(not(feature = "strict"))]
 #[test]
 fn pack_reduce_exp_too_big() {
 	let coefficient = 36_028_797_018_964;
 	let exponent    = 130;
-	let expect = dec64::NAN;
 	let result = Dec64::pack(coefficient, exponent);
 
 	assert!(result.is_nan());
-	assert_eq!(result, expect);
 }
 
 #[test]
@@ -144,3 +142,38 @@ fn pack_increase_exp_too_small() {
 	assert!(result.is_zero());
 	assert_eq!(result, expect);
 }
+
+#[test]
+fn pack_with_ties() {
+	let tie = 123456789012345675;
+
+	assert_eq!(Dec64::pack_with(tie, 0, RoundingMode::HalfAwayFromZero), Dec64::from_parts(12345678901234568, 1));
+	assert_eq!(Dec64::pack_with(tie, 0, RoundingMode::HalfEven), Dec64::from_parts(12345678901234568, 1));
+	assert_eq!(Dec64::pack_with(tie - 10, 0, RoundingMode::HalfEven), Dec64::from_parts(12345678901234566, 1));
+	assert_eq!(Dec64::pack_with(tie, 0, RoundingMode::HalfTowardZero), Dec64::from_parts(12345678901234567, 1));
+	assert_eq!(Dec64::pack_with(-tie, 0, RoundingMode::HalfTowardZero), Dec64::from_parts(-12345678901234567, 1));
+	assert_eq!(Dec64::pack_with(-tie, 0, RoundingMode::HalfAwayFromZero), Dec64::from_parts(-12345678901234568, 1));
+}
+
+#[test]
+fn pack_with_directed() {
+	let value = 123456789012345671;
+
+	assert_eq!(Dec64::pack_with(value, 0, RoundingMode::Ceiling), Dec64::from_parts(12345678901234568, 1));
+	assert_eq!(Dec64::pack_with(value, 0, RoundingMode::Floor), Dec64::from_parts(12345678901234567, 1));
+	assert_eq!(Dec64::pack_with(-value, 0, RoundingMode::Ceiling), Dec64::from_parts(-12345678901234567, 1));
+	assert_eq!(Dec64::pack_with(-value, 0, RoundingMode::Floor), Dec64::from_parts(-12345678901234568, 1));
+	assert_eq!(Dec64::pack_with(-value, 0, RoundingMode::TowardZero), Dec64::from_parts(-12345678901234567, 1));
+	assert_eq!(Dec64::pack_with(-value, 0, RoundingMode::AwayFromZero), Dec64::from_parts(-12345678901234568, 1));
+
+	// Below the smallest exponent, directed rounding may still round away from zero.
+	assert_eq!(Dec64::pack_with(1, -130, RoundingMode::Ceiling), dec64::MIN_POSITIVE);
+	assert_eq!(Dec64::pack_with(-1, -130, RoundingMode::Ceiling), dec64::ZERO);
+	assert_eq!(Dec64::pack_with(-1, -130, RoundingMode::Floor), Dec64::from_parts(-1, -127));
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn pack_with_overflow() {
+	assert!(Dec64::pack_with(1, 200, RoundingMode::TowardZero).is_nan());
+}
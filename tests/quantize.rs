@@ -0,0 +1,102 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::OutOfRange;
+use dec64::RoundingMode;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+
+use common::parts;
+
+#[test]
+fn quantize_scale_up() {
+	let mode = RoundingMode::HalfEven;
+
+	assert_eq!(Dec64::from_parts(12, 0).quantize(-2, mode).map(parts), Ok((1200, -2)));
+	assert_eq!(Dec64::from_parts(-15, -1).quantize(-2, mode).map(parts), Ok((-150, -2)));
+	assert_eq!(ZERO.quantize(-2, mode).map(parts), Ok((0, -2)));
+	assert_eq!(Dec64::from_parts(1, 0).quantize(-16, mode).map(parts), Ok((10000000000000000, -16)));
+}
+
+#[test]
+fn quantize_out_of_range() {
+	let mode = RoundingMode::HalfEven;
+
+	assert_eq!(Dec64::from_parts(1, 0).quantize(-17, mode), Err(OutOfRange));
+	assert_eq!(Dec64::from_parts(36028797018963967, 0).quantize(-1, mode), Err(OutOfRange));
+	assert_eq!(dec64::MAX.quantize(-127, mode), Err(OutOfRange));
+	assert_eq!(NAN.quantize(0, mode), Err(OutOfRange));
+	assert_eq!(NAN_NAN.quantize(0, mode), Err(OutOfRange));
+	assert_eq!(Dec64::from_parts(1, 0).quantize(-128, mode), Err(OutOfRange));
+}
+
+#[test]
+fn quantize_half_modes() {
+	let values = [
+		Dec64::from_parts(1225, -3),
+		Dec64::from_parts(1235, -3),
+		Dec64::from_parts(-1225, -3),
+		Dec64::from_parts(12251, -4),
+	];
+
+	let round = |mode| -> Vec<i64> {
+		values.iter().map(|value| value.quantize(-2, mode).unwrap().coefficient()).collect()
+	};
+
+	assert_eq!(round(RoundingMode::HalfAwayFromZero), [123, 124, -123, 123]);
+	assert_eq!(round(RoundingMode::HalfEven), [122, 124, -122, 123]);
+	assert_eq!(round(RoundingMode::HalfTowardZero), [122, 123, -122, 123]);
+}
+
+#[test]
+fn quantize_directed_modes() {
+	let values = [
+		Dec64::from_parts(1221, -3),
+		Dec64::from_parts(-1221, -3),
+		Dec64::from_parts(1220, -3),
+	];
+
+	let round = |mode| -> Vec<i64> {
+		values.iter().map(|value| value.quantize(-2, mode).unwrap().coefficient()).collect()
+	};
+
+	assert_eq!(round(RoundingMode::AwayFromZero), [123, -123, 122]);
+	assert_eq!(round(RoundingMode::TowardZero), [122, -122, 122]);
+	assert_eq!(round(RoundingMode::Ceiling), [123, -122, 122]);
+	assert_eq!(round(RoundingMode::Floor), [122, -123, 122]);
+}
+
+#[test]
+fn quantize_far_scale_down() {
+	let tiny = Dec64::from_parts(3, -40);
+
+	assert_eq!(tiny.quantize(-2, RoundingMode::HalfEven).map(parts), Ok((0, -2)));
+	assert_eq!(tiny.quantize(-2, RoundingMode::Ceiling).map(parts), Ok((1, -2)));
+	assert_eq!((-tiny).quantize(-2, RoundingMode::Ceiling).map(parts), Ok((0, -2)));
+	assert_eq!((-tiny).quantize(-2, RoundingMode::Floor).map(parts), Ok((-1, -2)));
+	assert_eq!(dec64::MAX.quantize(127, RoundingMode::HalfEven).map(parts), Ok((dec64::MAX_COEFFICIENT, 127)));
+}
+
+#[test]
+fn rounding_mode_default() {
+	assert_eq!(RoundingMode::default(), RoundingMode::HalfAwayFromZero);
+}
+
+#[test]
+fn quantize_inexact_flag() {
+	let quantize = |value: Dec64, exponent| {
+		Context::set_current(Context::default());
+		let result = value.quantize(exponent, RoundingMode::HalfEven);
+		(result.map(|value| value.coefficient()), Context::current().flags().inexact)
+	};
+
+	assert_eq!(quantize(Dec64::from_parts(1220, -3), -2), (Ok(122), false));
+	assert_eq!(quantize(Dec64::from_parts(12, 0), -2), (Ok(1200), false));
+	assert_eq!(quantize(Dec64::from_parts(1225, -3), -2), (Ok(122), true));
+	assert_eq!(quantize(Dec64::from_parts(3, -40), -2), (Ok(0), true));
+	assert_eq!(quantize(Dec64::from_parts(1, 0), -17), (Err(OutOfRange), false));
+}
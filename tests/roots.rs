@@ -0,0 +1,132 @@
+extern crate dec64;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::RoundingMode;
+use dec64::ZERO;
+use dec64::consts::SQRT_2;
+use dec64::consts::SQRT_3;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::ONE;
+use dec64::more_consts::normal::TWO;
+
+#[test]
+fn sqrt_perfect_squares() {
+	assert_eq!(Dec64::from_parts(225, -2).sqrt(), Dec64::from_parts(15, -1));
+	assert_eq!(Dec64::from_parts(144, 0).sqrt(), Dec64::from_parts(12, 0));
+	assert_eq!(Dec64::from_parts(1, -4).sqrt(), Dec64::from_parts(1, -2));
+	assert_eq!(Dec64::from_parts(1, 100).sqrt(), Dec64::from_parts(1, 50));
+}
+
+#[test]
+fn sqrt_exact_result_is_trimmed() {
+	let result = Dec64::from_parts(225, -2).sqrt();
+
+	assert_eq!(result.coefficient(), 15);
+	assert_eq!(result.exponent(), -1);
+}
+
+#[test]
+fn sqrt_consts() {
+	assert_eq!(TWO.sqrt(), SQRT_2);
+	assert_eq!(Dec64::from_parts(3, 0).sqrt(), SQRT_3);
+}
+
+#[test]
+fn sqrt_rounding() {
+	// sqrt(10) = 3.16227766016837933|2
+	assert_eq!(Dec64::from_parts(10, 0).sqrt(), Dec64::from_parts(31622776601683793, -16));
+	// sqrt(0.5) = 0.70710678118654752|44
+	assert_eq!(Dec64::from_parts(5, -1).sqrt(), Dec64::from_parts(7071067811865475, -16));
+}
+
+#[test]
+fn sqrt_rounding_modes() {
+	let sqrt = |mode, number: Dec64| {
+		Context::set_current(Context::new(mode));
+		let root = number.sqrt();
+		(root.coefficient(), root.exponent(), Context::current().flags().inexact)
+	};
+	let n193 = Dec64::from_parts(193, 0);
+
+	// The square root of 193 is 13.8924439894498045..., just above the tie.
+	assert_eq!(sqrt(RoundingMode::HalfEven, n193), (13892443989449805, -15, true));
+	assert_eq!(sqrt(RoundingMode::HalfTowardZero, n193), (13892443989449805, -15, true));
+	assert_eq!(sqrt(RoundingMode::Floor, TWO), (1414213562373095, -15, true));
+	assert_eq!(sqrt(RoundingMode::Ceiling, TWO), (14142135623730951, -16, true));
+	assert_eq!(sqrt(RoundingMode::TowardZero, TWO), (1414213562373095, -15, true));
+	assert_eq!(sqrt(RoundingMode::Ceiling, Dec64::from_parts(225, -2)), (15, -1, false));
+}
+
+#[test]
+fn sqrt_zero() {
+	assert_eq!(ZERO.sqrt(), ZERO);
+	assert_eq!(ZIP.sqrt(), ZERO);
+}
+
+#[test]
+fn sqrt_nan() {
+	assert!(NAN.sqrt().is_nan());
+	assert_eq!(NAN_NAN.sqrt(), NAN_NAN);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn sqrt_invalid() {
+	assert!(Dec64::from_parts(-1, 0).sqrt().is_nan());
+	assert!(dec64::MIN.sqrt().is_nan());
+}
+
+#[test]
+fn cbrt_exact() {
+	assert_eq!(Dec64::from_parts(27, 0).cbrt(), Dec64::from_parts(3, 0));
+	assert_eq!(Dec64::from_parts(-8, 0).cbrt(), Dec64::from_parts(-2, 0));
+	assert_eq!(Dec64::from_parts(1030301, -6).cbrt(), Dec64::from_parts(101, -2));
+	assert_eq!(Dec64::from_parts(1, -3).cbrt(), Dec64::from_parts(1, -1));
+}
+
+#[test]
+fn cbrt_rounding() {
+	// cbrt(2) = 1.25992104989487316|5
+	assert_eq!(TWO.cbrt(), Dec64::from_parts(12599210498948732, -16));
+}
+
+#[test]
+fn nth_root() {
+	assert_eq!(Dec64::from_parts(32, 0).nth_root(5), TWO);
+	assert_eq!(Dec64::from_parts(-32, 0).nth_root(5), Dec64::from_parts(-2, 0));
+	assert_eq!(Dec64::from_parts(4, 0).nth_root(-2), Dec64::from_parts(5, -1));
+	assert_eq!(ONE.nth_root(1000), ONE);
+	// 10^(1/7) = 1.38949549437313763|7
+	assert_eq!(Dec64::from_parts(10, 0).nth_root(7), Dec64::from_parts(13894954943731376, -16));
+}
+
+#[test]
+fn nth_root_nan() {
+	assert_eq!(NAN_NAN.nth_root(3), NAN_NAN);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn nth_root_invalid() {
+	assert!(TWO.nth_root(0).is_nan());
+	assert!(Dec64::from_parts(-1, 0).nth_root(4).is_nan());
+	assert!(ZERO.nth_root(-3).is_nan());
+}
+
+#[test]
+fn hypot() {
+	assert_eq!(Dec64::from_parts(3, 0).hypot(Dec64::from_parts(-4, 0)), Dec64::from_parts(5, 0));
+	assert_eq!(ONE.hypot(ONE), SQRT_2);
+	assert_eq!(ZERO.hypot(TWO), TWO);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn hypot_overflow() {
+	assert!(dec64::MAX.hypot(dec64::MAX).is_nan());
+	assert!(NAN.hypot(ONE).is_nan());
+	assert_eq!(ONE.hypot(NAN_NAN), NAN_NAN);
+}
@@ -0,0 +1,77 @@
+extern crate dec64;
+
+mod common;
+
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+
+use common::parts;
+
+#[test]
+fn to_scaled_i64() {
+	let value = Dec64::from_parts(1234, -2);
+
+	assert_eq!(value.to_scaled_i64(2), Some(1234));
+	assert_eq!(value.to_scaled_i64(4), Some(123400));
+	assert_eq!(Dec64::from_parts(1200, -2).to_scaled_i64(0), Some(12));
+	assert_eq!(Dec64::from_parts(-5, 3).to_scaled_i64(2), Some(-500000));
+	assert_eq!(ZIP.to_scaled_i64(2), Some(0));
+}
+
+#[test]
+fn to_scaled_i64_none() {
+	assert_eq!(Dec64::from_parts(1234, -2).to_scaled_i64(1), None);
+	assert_eq!(Dec64::from_parts(1, -30).to_scaled_i64(2), None);
+	assert_eq!(Dec64::from_parts(1, 17).to_scaled_i64(2), None);
+	assert_eq!(dec64::MAX.to_scaled_i64(0), None);
+	assert_eq!(NAN.to_scaled_i64(2), None);
+}
+
+#[test]
+fn to_scaled_i64_extreme_scale() {
+	assert_eq!(Dec64::from_parts(1, 1).to_scaled_i64(i32::MAX), None);
+	assert_eq!(Dec64::from_parts(1, -1).to_scaled_i64(i32::MIN), None);
+	assert_eq!(ZIP.to_scaled_i64(i32::MIN), Some(0));
+}
+
+#[test]
+fn from_scaled_i64() {
+	assert_eq!(parts(Dec64::from_scaled_i64(1234, 2)), (1234, -2));
+	assert_eq!(parts(Dec64::from_scaled_i64(-7, -3)), (-7, 3));
+	assert_eq!(parts(Dec64::from_scaled_i64(0, 2)), parts(ZERO));
+	// i64::MAX = 9223372036854775|807
+	assert_eq!(parts(Dec64::from_scaled_i64(i64::MAX, 2)), (9223372036854776, 1));
+	assert_eq!(Dec64::from_scaled_i64(1, 200), ZERO);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn from_scaled_i64_overflow() {
+	assert!(Dec64::from_scaled_i64(1, -200).is_nan());
+}
+
+#[test]
+fn to_unscaled() {
+	assert_eq!(Dec64::from_parts(1234, -2).to_unscaled(), Some((1234, 2)));
+	assert_eq!(Dec64::from_parts(-5, 3).to_unscaled(), Some((-5, -3)));
+	assert_eq!(NAN_NAN.to_unscaled(), None);
+}
+
+#[test]
+fn from_unscaled() {
+	assert_eq!(parts(Dec64::from_unscaled(1234, 2)), (1234, -2));
+	// 10^30 + 1 = 10000000000000000|00000000000001
+	assert_eq!(parts(Dec64::from_unscaled(1_000_000_000_000_000_000_000_000_000_001, 10)), (10000000000000000, 4));
+	assert_eq!(parts(Dec64::from_unscaled(i128::MIN, 0)), (-17014118346046923, 22));
+	assert_eq!(Dec64::from_unscaled(0, i32::MIN), ZERO);
+	assert_eq!(Dec64::from_unscaled(1, i32::MAX), ZERO);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn from_unscaled_overflow() {
+	assert!(Dec64::from_unscaled(1, i32::MIN).is_nan());
+}
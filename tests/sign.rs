@@ -0,0 +1,69 @@
+extern crate dec64;
+
+use dec64::Dec64;
+use dec64::Dec64Category;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::HALF;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+
+#[test]
+fn abs() {
+	assert_eq!(NEG_ONE.abs(), ONE);
+	assert_eq!(ONE.abs(), ONE);
+	assert_eq!(Dec64::from_parts(-15, -1).abs(), Dec64::from_parts(15, -1));
+	assert_eq!(ZERO.abs(), ZERO);
+	assert!(NAN.abs().is_nan());
+	assert_eq!(Dec64::from_parts(-3, -128).abs(), Dec64::from_parts(-3, -128));
+}
+
+#[test]
+fn abs_min_coefficient() {
+	let min = Dec64::from_parts(dec64::MIN_COEFFICIENT, 0);
+	let abs = min.abs();
+
+	assert_eq!(abs.coefficient(), 3602879701896397);
+	assert_eq!(abs.exponent(), 1);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn abs_min() {
+	assert!(dec64::MIN.abs().is_nan());
+}
+
+#[test]
+fn signum() {
+	assert_eq!(Dec64::from_parts(42, -7).signum(), ONE);
+	assert_eq!(dec64::MIN.signum(), NEG_ONE);
+	assert_eq!(ZIP.signum(), ZERO);
+	assert_eq!(NAN_NAN.signum(), NAN_NAN);
+}
+
+#[test]
+fn sign_predicates() {
+	assert!(NEG_ONE.is_sign_negative());
+	assert!(!NEG_ONE.is_sign_positive());
+	assert!(ONE.is_sign_positive());
+	assert!(ZERO.is_sign_positive());
+	assert!(!ZERO.is_sign_negative());
+	assert!(!NAN.is_sign_positive());
+	assert!(!NAN.is_sign_negative());
+	assert!(!Dec64::from_parts(-3, -128).is_sign_negative());
+}
+
+#[test]
+fn classify() {
+	assert_eq!(NAN.classify(), Dec64Category::Nan);
+	assert_eq!(NAN_NAN.classify(), Dec64Category::NonNormalNan);
+	assert_eq!(ZERO.classify(), Dec64Category::Zero);
+	assert_eq!(ZIP.classify(), Dec64Category::NonNormalZero);
+	assert_eq!(NEG_ONE.classify(), Dec64Category::Integer);
+	assert_eq!(Dec64::from_parts(1200, -2).classify(), Dec64Category::Integer);
+	assert_eq!(Dec64::from_parts(5, 100).classify(), Dec64Category::Integer);
+	assert_eq!(HALF.classify(), Dec64Category::Fraction);
+	assert_eq!(dec64::MIN_POSITIVE.classify(), Dec64Category::Fraction);
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "strict")]
+
+extern crate dec64;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::ZERO;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+
+#[test]
+#[should_panic(expected = "Dec64::pack produced NaN (Overflow)")]
+fn strict_pack_overflow() {
+	Dec64::pack(1, 200);
+}
+
+#[test]
+#[should_panic(expected = "Dec64::add produced NaN (Overflow) from [")]
+fn strict_add_overflow() {
+	let _ = dec64::MAX + dec64::MAX;
+}
+
+#[test]
+#[should_panic(expected = "Dec64::exp produced NaN (Overflow) from [Dec64 { [--I] coef: 400 exp: 0 ")]
+fn strict_exp_overflow() {
+	Dec64::from_parts(400, 0).exp();
+}
+
+#[test]
+#[should_panic(expected = "Dec64::div produced NaN (DivisionByZero) from [Dec64 { [--I] coef: 1 exp: 0 ")]
+fn strict_div_operator() {
+	let _ = ONE / ZERO;
+}
+
+#[test]
+#[should_panic(expected = "Dec64::from produced NaN (Overflow) from [1e300]")]
+fn strict_from_f64_overflow() {
+	let _ = Dec64::from(1e300);
+}
+
+#[test]
+#[should_panic(expected = "Dec64::from produced NaN (NanOperand) from [NaN]")]
+fn strict_from_f64_nan() {
+	let _ = Dec64::from(f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "Context::div produced NaN (DivisionByZero)")]
+fn strict_division_by_zero() {
+	Context::default().div(ONE, ZERO);
+}
+
+#[test]
+#[should_panic(expected = "Dec64::mul_div produced NaN (DivisionByZero)")]
+fn strict_mul_div_by_zero() {
+	ONE.mul_div(ONE, ZERO);
+}
+
+#[test]
+#[should_panic(expected = "Dec64::sqrt produced NaN (Invalid)")]
+fn strict_invalid() {
+	NEG_ONE.sqrt();
+}
+
+#[test]
+fn strict_checked() {
+	// Checked operations return None rather than produce a NaN.
+	assert_eq!(Dec64::from_parts(10, 0).checked_powi(200), None);
+	assert_eq!(ZERO.checked_powi(-1), None);
+}
+
+#[test]
+fn strict_nan_operand() {
+	// NaNs passed in are not produced by the operation, so they do not panic.
+	assert!((NAN + ONE).is_nan());
+	assert_eq!(NAN_NAN.sqrt(), NAN_NAN);
+	assert_eq!(Dec64::nan_with_payload(5).nan_payload(), Some(5));
+}
@@ -155,6 +155,23 @@ fn roundtrip_tiny_neg_f64() {
     assert_eq!(num, -1.23456e-100);
 }
 
+#[cfg(not(feature = "strict"))]
+#[test]
+fn from_f64_nan() {
+    assert!(Dec64::from(f64::NAN).is_nan());
+    assert!(Dec64::from(f64::INFINITY).is_nan());
+    assert!(Dec64::from(f64::NEG_INFINITY).is_nan());
+    assert!(Dec64::from(1e300).is_nan());
+    assert!(Dec64::from(f32::NAN).is_nan());
+}
+
+#[test]
+fn from_f64_zero() {
+    assert_eq!(Dec64::from(0.0_f64), dec64::ZERO);
+    assert_eq!(Dec64::from(-0.0_f64), dec64::ZERO);
+    assert_eq!(Dec64::from(1e-300), dec64::ZERO);
+}
+
 #[test]
 fn compose_f64_pi() {
     let dec = Dec64::from_parts(3141592653589793, -15);
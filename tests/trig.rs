@@ -0,0 +1,246 @@
+extern crate dec64;
+
+use dec64::Context;
+use dec64::Dec64;
+use dec64::NAN;
+use dec64::RoundingMode;
+use dec64::ZERO;
+use dec64::consts::FRAC_1_SQRT_2;
+use dec64::consts::FRAC_1_SQRT_3;
+use dec64::consts::FRAC_PI_2;
+use dec64::consts::FRAC_PI_3;
+use dec64::consts::FRAC_PI_4;
+use dec64::consts::FRAC_PI_6;
+use dec64::consts::PI;
+use dec64::consts::SQRT_3;
+use dec64::more_consts::HALF;
+use dec64::more_consts::NAN_NAN;
+use dec64::more_consts::NEG_PI;
+use dec64::more_consts::ZIP;
+use dec64::more_consts::normal::MAXINT;
+use dec64::more_consts::normal::NEG_ONE;
+use dec64::more_consts::normal::ONE;
+
+#[test]
+fn sin_zero() {
+	assert_eq!(ZERO.sin(), ZERO);
+	assert_eq!(ZIP.sin(), ZERO);
+	assert_eq!(ZERO.cos(), ONE);
+	assert_eq!(ZERO.tan(), ZERO);
+}
+
+#[test]
+fn sin_pi_fractions() {
+	assert_eq!(PI.sin(), ZERO);
+	assert_eq!(NEG_PI.sin(), ZERO);
+	assert_eq!(FRAC_PI_2.sin(), ONE);
+	assert_eq!((-FRAC_PI_2).sin(), NEG_ONE);
+	assert_eq!(FRAC_PI_6.sin(), HALF);
+	assert_eq!(FRAC_PI_4.sin(), FRAC_1_SQRT_2);
+}
+
+#[test]
+fn cos_pi_fractions() {
+	assert_eq!(PI.cos(), NEG_ONE);
+	assert_eq!(FRAC_PI_2.cos(), ZERO);
+	assert_eq!(FRAC_PI_3.cos(), HALF);
+	assert_eq!(FRAC_PI_4.cos(), FRAC_1_SQRT_2);
+}
+
+#[test]
+fn tan_pi_fractions() {
+	assert_eq!(PI.tan(), ZERO);
+	assert_eq!(FRAC_PI_4.tan(), ONE);
+	assert_eq!(FRAC_PI_3.tan(), SQRT_3);
+	assert_eq!(FRAC_PI_6.tan(), FRAC_1_SQRT_3);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn tan_pole() {
+	assert!(FRAC_PI_2.tan().is_nan());
+}
+
+#[test]
+fn pi_fractions_directed() {
+	// The multiples of π/12 are recognized whatever the rounding mode.
+	for &mode in &[RoundingMode::Ceiling, RoundingMode::Floor, RoundingMode::TowardZero] {
+		Context::set_current(Context::new(mode));
+		assert_eq!(PI.sin(), ZERO);
+		assert_eq!(FRAC_PI_3.cos(), HALF);
+		assert_eq!(FRAC_PI_6.sin(), HALF);
+		assert_eq!(FRAC_PI_4.tan(), ONE);
+		assert_eq!((-FRAC_PI_4).tan(), NEG_ONE);
+		assert_eq!(NEG_PI.cos(), NEG_ONE);
+	}
+	Context::set_current(Context::default());
+}
+
+#[test]
+fn sin_pi_multiples() {
+	// 1000π = 3141.59265358979323|8
+	let thousand_pi = Dec64::from_parts(31415926535897932, -13);
+
+	assert_eq!(thousand_pi.sin(), ZERO);
+	assert_eq!(thousand_pi.cos(), ONE);
+}
+
+#[test]
+fn sin_close_to_pi() {
+	// π - 3.141592653589793 = 2.384626433832795|03e-16
+	let value = Dec64::from_parts(3141592653589793, -15);
+
+	assert_eq!(value.sin(), Dec64::from_parts(2384626433832795, -31));
+	assert_eq!(value.cos(), NEG_ONE);
+}
+
+#[test]
+fn sin_cos_one() {
+	// sin(1) = 0.84147098480789650|67
+	// cos(1) = 0.54030230586813971|74
+	let (sin, cos) = ONE.sin_cos();
+
+	assert_eq!(sin, Dec64::from_parts(8414709848078965, -16));
+	assert_eq!(cos, Dec64::from_parts(5403023058681397, -16));
+	// tan(1) = 1.5574077246549022|31
+	assert_eq!(ONE.tan(), Dec64::from_parts(15574077246549022, -16));
+}
+
+#[test]
+fn sin_large() {
+	// sin(1e17) = -0.46453010483537269|6
+	// cos(1e17) = -0.88555732829763068|5
+	let (sin, cos) = Dec64::from_parts(1, 17).sin_cos();
+
+	assert_eq!(sin, Dec64::from_parts(-4645301048353727, -16));
+	assert_eq!(cos, Dec64::from_parts(-8855573282976307, -16));
+}
+
+#[test]
+fn sin_tiny() {
+	let tiny = Dec64::from_parts(1, -20);
+
+	assert_eq!(tiny.sin(), tiny);
+	assert_eq!(tiny.cos(), ONE);
+	assert_eq!(tiny.tan(), tiny);
+}
+
+#[test]
+fn sin_nan() {
+	assert!(NAN.sin().is_nan());
+	assert_eq!(NAN_NAN.cos(), NAN_NAN);
+	assert!(NAN.tan().is_nan());
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn sin_huge() {
+	assert!(Dec64::from_parts(1, 18).sin().is_nan());
+	assert!(dec64::MAX.cos().is_nan());
+
+	let (sin, cos) = dec64::MIN.sin_cos();
+	assert!(sin.is_nan());
+	assert!(cos.is_nan());
+}
+
+#[test]
+fn asin_special_values() {
+	assert_eq!(ZERO.asin(), ZERO);
+	assert_eq!(ONE.asin(), FRAC_PI_2);
+	assert_eq!(NEG_ONE.asin(), -FRAC_PI_2);
+	assert_eq!(HALF.asin(), FRAC_PI_6);
+	assert_eq!(FRAC_1_SQRT_2.asin(), FRAC_PI_4);
+}
+
+#[test]
+fn acos_special_values() {
+	assert_eq!(ONE.acos(), ZERO);
+	assert_eq!(ZERO.acos(), FRAC_PI_2);
+	assert_eq!(NEG_ONE.acos(), PI);
+	assert_eq!(HALF.acos(), FRAC_PI_3);
+}
+
+#[test]
+fn acos_close_to_one() {
+	// acos(0.9999999999999999) = 1.41421356237309506|06e-8
+	let value = Dec64::from_parts(9999999999999999, -16);
+
+	assert_eq!(value.acos(), Dec64::from_parts(14142135623730951, -24));
+}
+
+#[test]
+fn asin_acos_negative() {
+	let value = Dec64::from_parts(-7, -1);
+
+	// asin(-0.7) = -0.77539749661075306|37
+	assert_eq!(value.asin(), Dec64::from_parts(-7753974966107531, -16));
+	// acos(-0.7) = 2.34619382340564968|3
+	assert_eq!(value.acos(), Dec64::from_parts(23461938234056497, -16));
+}
+
+#[test]
+fn asin_acos_nan() {
+	assert!(NAN.asin().is_nan());
+	assert_eq!(NAN_NAN.acos(), NAN_NAN);
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn asin_acos_invalid() {
+	assert!(Dec64::from_parts(10000000000000001, -16).asin().is_nan());
+	assert!(Dec64::from_parts(-2, 0).acos().is_nan());
+}
+
+#[test]
+fn atan_special_values() {
+	assert_eq!(ZERO.atan(), ZERO);
+	assert_eq!(ONE.atan(), FRAC_PI_4);
+	assert_eq!(NEG_ONE.atan(), -FRAC_PI_4);
+	assert_eq!(SQRT_3.atan(), FRAC_PI_3);
+	assert_eq!(MAXINT.atan(), FRAC_PI_2);
+	assert_eq!(dec64::MIN.atan(), -FRAC_PI_2);
+	assert!(NAN.atan().is_nan());
+}
+
+#[test]
+fn atan_tiny() {
+	let tiny = Dec64::from_parts(1, -20);
+
+	assert_eq!(tiny.atan(), tiny);
+	assert_eq!(tiny.asin(), tiny);
+}
+
+#[test]
+fn atan2_quadrants() {
+	// 3π/4 = 2.35619449019234492|9
+	let three_quarters_pi = Dec64::from_parts(23561944901923449, -16);
+
+	assert_eq!(ONE.atan2(ONE), FRAC_PI_4);
+	assert_eq!(ONE.atan2(NEG_ONE), three_quarters_pi);
+	assert_eq!(NEG_ONE.atan2(NEG_ONE), -three_quarters_pi);
+	assert_eq!(NEG_ONE.atan2(ONE), -FRAC_PI_4);
+}
+
+#[test]
+fn atan2_axes() {
+	assert_eq!(ZERO.atan2(ZERO), ZERO);
+	assert_eq!(ZERO.atan2(ONE), ZERO);
+	assert_eq!(ZERO.atan2(NEG_ONE), PI);
+	assert_eq!(ONE.atan2(ZERO), FRAC_PI_2);
+	assert_eq!(NEG_ONE.atan2(ZIP), -FRAC_PI_2);
+}
+
+#[test]
+fn atan2_bearing() {
+	// atan2(3, 4) = 0.64350110879328438|68
+	let expect = Dec64::from_parts(6435011087932844, -16);
+
+	assert_eq!(Dec64::from_parts(3, 0).atan2(Dec64::from_parts(4, 0)), expect);
+	assert_eq!(Dec64::from_parts(3, 10).atan2(Dec64::from_parts(4, 10)), expect);
+}
+
+#[test]
+fn atan2_nan() {
+	assert!(NAN.atan2(ONE).is_nan());
+	assert_eq!(ONE.atan2(NAN_NAN), NAN_NAN);
+}